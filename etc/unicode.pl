@@ -0,0 +1,178 @@
+#!/usr/bin/env perl
+#
+# Generate the Unicode tables in src/charclass from the Unicode
+# database that ships with Perl.
+#
+# Usage: perl etc/unicode.pl
+#
+
+use strict;
+use warnings;
+
+use Unicode::UCD;
+
+my $version = Unicode::UCD::UnicodeVersion();
+
+sub char_literal {
+    my ($c) = @_;
+    if ($c < 0x80) {
+        return sprintf("'\\x%02x'", $c);
+    } elsif ($c < 0x10000) {
+        return sprintf("'\\u%04X'", $c);
+    } else {
+        return sprintf("'\\U%08X'", $c);
+    }
+}
+
+sub header {
+    my ($fh, $doc) = @_;
+    print $fh "// Generated by etc/unicode.pl from Unicode $version.  Do not edit.\n\n";
+    print $fh "//! $doc\n\n";
+}
+
+# Case folding: group code points into equivalence classes under the
+# simple (one-to-one) case folding.
+sub casefold {
+    my %orbits;
+    my $folds = Unicode::UCD::all_casefolds();
+    for my $c (keys %$folds) {
+        my $simple = $folds->{$c}{simple};
+        next unless $simple;
+        push @{$orbits{hex $simple}}, $c;
+    }
+
+    my @pairs;
+    for my $folded (keys %orbits) {
+        my $orbit = [$folded, @{$orbits{$folded}}];
+        for my $a (@$orbit) {
+            for my $b (@$orbit) {
+                push @pairs, [$a, $b] if $a != $b;
+            }
+        }
+    }
+    @pairs = sort { $a->[0] <=> $b->[0] || $a->[1] <=> $b->[1] } @pairs;
+
+    open(my $fh, '>', 'src/charclass/casefold.rs') or die $!;
+    header($fh, 'Simple case folding tables');
+    print $fh "/// Pairs of code points that are equal when case is ignored, sorted\n";
+    print $fh "/// by the first code point.  Every pair appears in both orders.\n";
+    print $fh "pub static pairs: &'static [(char, char)] = &[\n";
+    for my $pair (@pairs) {
+        printf $fh "    (%s, %s),\n", char_literal($pair->[0]), char_literal($pair->[1]);
+    }
+    print $fh "];\n";
+    close($fh);
+}
+
+# Return the ranges of code points that have any of the given
+# properties, leaving out surrogates since they cannot be represented
+# as a `char`.
+sub ranges {
+    my @all;
+    for my $prop (@_) {
+        my @invlist = Unicode::UCD::prop_invlist($prop);
+        die "unknown property $prop" unless @invlist;
+        push @invlist, 0x110000 if @invlist % 2;
+        while (@invlist) {
+            my ($lo, $end) = splice(@invlist, 0, 2);
+            push @all, [$lo, $end - 1];
+        }
+    }
+    @all = sort { $a->[0] <=> $b->[0] } @all;
+
+    # Merge overlapping and adjacent ranges
+    my @merged;
+    for my $range (@all) {
+        if (@merged && $range->[0] <= $merged[-1][1] + 1) {
+            $merged[-1][1] = $range->[1] if $range->[1] > $merged[-1][1];
+        } else {
+            push @merged, [@$range];
+        }
+    }
+
+    my @ranges;
+    for my $range (@merged) {
+        my ($lo, $hi) = @$range;
+        if ($lo < 0xD800 && $hi > 0xDFFF) {
+            push @ranges, [$lo, 0xD7FF], [0xE000, $hi];
+        } elsif ($hi < 0xD800 || $lo > 0xDFFF) {
+            push @ranges, [$lo, $hi];
+        } elsif ($lo < 0xD800) {
+            push @ranges, [$lo, 0xD7FF];
+        } elsif ($hi > 0xDFFF) {
+            push @ranges, [0xE000, $hi];
+        }
+    }
+    return @ranges;
+}
+
+sub print_class {
+    my ($fh, $name, @ranges) = @_;
+    print $fh "pub static $name: CharClass = CCStatic([\n";
+    for my $range (@ranges) {
+        printf $fh "    (%s, %s),\n", char_literal($range->[0]), char_literal($range->[1]);
+    }
+    print $fh "]);\n\n";
+}
+
+# Print a function that looks up a class by any of its names.
+sub print_lookup {
+    my ($fh, $fn, $doc, @classes) = @_;
+    print $fh "$doc";
+    print $fh "pub fn $fn(name: &str) -> Option<CharClass> {\n";
+    print $fh "    match name {\n";
+    for my $class (@classes) {
+        my ($static, @aliases) = @$class;
+        my $pattern = join(' | ', map { "\"$_\"" } @aliases);
+        print $fh "        $pattern => Some($static.clone()),\n";
+    }
+    print $fh "        _ => None\n";
+    print $fh "    }\n";
+    print $fh "}\n";
+}
+
+# General categories and scripts.
+sub unicode {
+    open(my $fh, '>', 'src/charclass/unicode.rs') or die $!;
+    header($fh, 'Unicode character classes');
+    print $fh "use super::{CharClass, CCStatic};\n\n\n";
+
+    my @categories;
+    for my $gc (Unicode::UCD::prop_values('gc')) {
+        my @ranges = eval { ranges("gc=$gc") };
+        next unless @ranges;
+        my @aliases = Unicode::UCD::prop_value_aliases('gc', $gc);
+        print_class($fh, $gc, @ranges);
+        push @categories, [$gc, @aliases];
+    }
+
+    my @scripts;
+    for my $sc (Unicode::UCD::prop_values('Script')) {
+        my @aliases = Unicode::UCD::prop_value_aliases('Script', $sc);
+        next unless @aliases;
+        my @ranges = eval { ranges("Script=$sc") };
+        next unless @ranges;
+        print_class($fh, $aliases[1], @ranges);
+        push @scripts, [$aliases[1], $aliases[1]];
+    }
+
+    print $fh "/// Whitespace, for `\\s` in Unicode mode.\n";
+    print_class($fh, 'space', ranges('White_Space'));
+
+    print $fh "/// Word characters, for `\\w` in Unicode mode.\n";
+    print_class($fh, 'word', ranges('Alphabetic', 'gc=M', 'gc=Nd', 'gc=Pc'));
+
+    print_lookup($fh, 'general_category', <<'DOC', @categories);
+
+/// Look up a general category by its short or long name, e.g. `Lu` or
+/// `Uppercase_Letter`.
+DOC
+    print_lookup($fh, 'script', <<'DOC', @scripts);
+
+/// Look up a script by name, e.g. `Greek`.
+DOC
+    close($fh);
+}
+
+casefold();
+unicode();
@@ -0,0 +1,234 @@
+//!
+//! Bounded backtracking matcher.
+//!
+//! This runs the same program as the VM, but explores one thread at a
+//! time, trying alternatives in priority order.  That gives the same
+//! leftmost-first results without copying registers around on every
+//! step.  To keep it from taking exponential time, every `(pc, position)`
+//! pair is visited at most once: if a thread got there before and
+//! failed, any later one would fail too.  The bitset that tracks this
+//! grows with both the program and the input, so it's only used when
+//! their product is small.
+//!
+
+use std::vec;
+
+use vm::{Inst, Assert, Class, Jump, Match, Range, Save};
+use vm::Context;
+
+
+/// The largest bitset, in bits, that the backtracker will allocate.
+static MAX_VISITED: uint = 256 * 1024;
+
+
+/// Return `true` if the backtracker should be used to run a program
+/// over `len` bytes of input.
+pub fn should_use(program: &[Inst], len: uint) -> bool {
+    match program.len().checked_mul(&(len + 1)) {
+        Some(bits) => bits <= MAX_VISITED,
+        None => false
+    }
+}
+
+
+///
+/// Run a program over the input.  If `anchored` is `true`, the match
+/// must begin at the start of the input; otherwise the leftmost one is
+/// found.
+///
+/// On success, return the registers of the match as byte offsets into
+/// `text`.
+///
+pub fn run(program: &[Inst], n_regs: uint, text: &str, anchored: bool)
+        -> Option<~[Option<u64>]> {
+    let width = text.len() + 1;
+    let mut b = Backtracker {
+        program: program,
+        text: text,
+        width: width,
+        visited: vec::from_elem((program.len() * width + 31) / 32, 0u32),
+        registers: vec::from_elem(n_regs, None),
+        jobs: ~[]
+    };
+
+    let mut pos = 0;
+    loop {
+        if b.try_at(pos) {
+            return Some(b.registers);
+        }
+        if anchored || pos == text.len() {
+            return None;
+        }
+        pos = text.char_range_at(pos).next;
+    }
+}
+
+
+/// Something left to do when backtracking.
+enum Job {
+    /// Run a thread from the given `pc` and position.
+    Explore(uint, uint),
+    /// Put back the old value of a register.
+    Restore(uint, Option<u64>)
+}
+
+
+struct Backtracker<'a> {
+    program: &'a [Inst],
+    text: &'a str,
+    /// The number of positions in the input, plus one for the end
+    width: uint,
+    visited: ~[u32],
+    registers: ~[Option<u64>],
+    jobs: ~[Job]
+}
+
+impl<'a> Backtracker<'a> {
+    /// Look for a match beginning at the given position.
+    fn try_at(&mut self, pos: uint) -> bool {
+        self.jobs.push(Explore(0, pos));
+        loop {
+            match self.jobs.pop() {
+                Some(Explore(pc, pos)) => if self.step(pc, pos) {
+                    return true;
+                },
+                Some(Restore(reg, old)) => self.registers[reg] = old,
+                None => return false
+            }
+        }
+    }
+
+    /// Follow a single thread until it matches or fails.  Alternatives
+    /// of lower priority are pushed onto the job stack along the way.
+    fn step(&mut self, mut pc: uint, mut pos: uint) -> bool {
+        let program = self.program;
+        loop {
+            if !self.visit(pc, pos) {
+                return false;
+            }
+            match program[pc] {
+                Jump(ref exits) => {
+                    if exits.is_empty() {
+                        return false;
+                    }
+                    for &exit in exits.slice_from(1).iter().rev() {
+                        self.jobs.push(Explore(exit, pos));
+                    }
+                    pc = exits[0];
+                },
+                Save(reg) => {
+                    let old = self.registers[reg];
+                    self.jobs.push(Restore(reg, old));
+                    self.registers[reg] = Some(pos as u64);
+                    pc += 1;
+                },
+                Assert(ref assertion) => {
+                    if !assertion.holds(&self.context(pos)) {
+                        return false;
+                    }
+                    pc += 1;
+                },
+                Match(..) => return true,
+                Range(lo, hi) => match self.char_at(pos) {
+                    Some((c, next)) if lo <= c && c <= hi => {
+                        pc += 1;
+                        pos = next;
+                    },
+                    _ => return false
+                },
+                Class(ref cc) => match self.char_at(pos) {
+                    Some((c, next)) if cc.includes(c) => {
+                        pc += 1;
+                        pos = next;
+                    },
+                    _ => return false
+                }
+            }
+        }
+    }
+
+    /// Mark the given state as visited.  Return `false` if it already
+    /// was.
+    fn visit(&mut self, pc: uint, pos: uint) -> bool {
+        let bit = pc * self.width + pos;
+        let (word, mask) = (bit / 32, 1u32 << (bit % 32));
+        if self.visited[word] & mask != 0 {
+            false
+        } else {
+            self.visited[word] |= mask;
+            true
+        }
+    }
+
+    /// Return the character at the given position, along with the
+    /// position after it.
+    fn char_at(&self, pos: uint) -> Option<(char, uint)> {
+        if pos < self.text.len() {
+            let r = self.text.char_range_at(pos);
+            Some((r.ch, r.next))
+        } else {
+            None
+        }
+    }
+
+    /// Return the characters surrounding the given position.
+    fn context(&self, pos: uint) -> Context {
+        let prev = if pos > 0 {
+            Some(self.text.char_range_at_reverse(pos).ch)
+        } else {
+            None
+        };
+        let (next, after) = match self.char_at(pos) {
+            Some((c, next)) => (Some(c), self.char_at(next).map(|(c, _)| c)),
+            None => (None, None)
+        };
+        Context { prev: prev, next: next, after: after }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::run;
+    use vm::VM;
+
+    /// Check that the backtracker agrees with the VM.
+    fn check(pattern: &str, text: &str) {
+        let re = ::compile(pattern).unwrap();
+        let expected = {
+            let mut vm = VM::unanchored(re.program);
+            for c in text.chars() {
+                vm.feed(c);
+            }
+            vm.finish();
+            vm.registers().map(|r| r.to_owned())
+        };
+        let actual = run(re.program, re.n_regs, text, false);
+        match (expected, actual) {
+            (None, None) => (),
+            (Some(e), Some(a)) => {
+                for (i, reg) in a.iter().enumerate() {
+                    let e_reg = if i < e.len() { e[i] } else { None };
+                    assert_eq!((pattern, i, *reg), (pattern, i, e_reg));
+                }
+            },
+            (e, a) => fail!("{}: expected {:?}, found {:?}", pattern, e, a)
+        }
+    }
+
+    #[test]
+    fn agrees_with_vm() {
+        check("a+", "baaab");
+        check("a+?", "baaab");
+        check("(a|ab)(c|bcd)(d*)", "abcd");
+        check("(a|b)*?b", "aabab");
+        check("(a+|b+)+c", "aabbac");
+        check("(?:(a)|b)*", "ab");
+        check(r"\bfoo\b", "foobar foo");
+        check(r"(?u)\b\w+\b", "x café");
+        check(r"x*\Z", "xx\n");
+        check("(?m)^b$", "a\nb\nc");
+        check("é(.)", "aébc");
+        check("z", "abc");
+    }
+}
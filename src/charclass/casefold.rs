@@ -0,0 +1,2982 @@
+// Generated by etc/unicode.pl from Unicode 14.0.0.  Do not edit.
+
+//! Simple case folding tables
+
+/// Pairs of code points that are equal when case is ignored, sorted
+/// by the first code point.  Every pair appears in both orders.
+pub static pairs: &'static [(char, char)] = &[
+    ('\x41', '\x61'),
+    ('\x42', '\x62'),
+    ('\x43', '\x63'),
+    ('\x44', '\x64'),
+    ('\x45', '\x65'),
+    ('\x46', '\x66'),
+    ('\x47', '\x67'),
+    ('\x48', '\x68'),
+    ('\x49', '\x69'),
+    ('\x4a', '\x6a'),
+    ('\x4b', '\x6b'),
+    ('\x4b', '\u212A'),
+    ('\x4c', '\x6c'),
+    ('\x4d', '\x6d'),
+    ('\x4e', '\x6e'),
+    ('\x4f', '\x6f'),
+    ('\x50', '\x70'),
+    ('\x51', '\x71'),
+    ('\x52', '\x72'),
+    ('\x53', '\x73'),
+    ('\x53', '\u017F'),
+    ('\x54', '\x74'),
+    ('\x55', '\x75'),
+    ('\x56', '\x76'),
+    ('\x57', '\x77'),
+    ('\x58', '\x78'),
+    ('\x59', '\x79'),
+    ('\x5a', '\x7a'),
+    ('\x61', '\x41'),
+    ('\x62', '\x42'),
+    ('\x63', '\x43'),
+    ('\x64', '\x44'),
+    ('\x65', '\x45'),
+    ('\x66', '\x46'),
+    ('\x67', '\x47'),
+    ('\x68', '\x48'),
+    ('\x69', '\x49'),
+    ('\x6a', '\x4a'),
+    ('\x6b', '\x4b'),
+    ('\x6b', '\u212A'),
+    ('\x6c', '\x4c'),
+    ('\x6d', '\x4d'),
+    ('\x6e', '\x4e'),
+    ('\x6f', '\x4f'),
+    ('\x70', '\x50'),
+    ('\x71', '\x51'),
+    ('\x72', '\x52'),
+    ('\x73', '\x53'),
+    ('\x73', '\u017F'),
+    ('\x74', '\x54'),
+    ('\x75', '\x55'),
+    ('\x76', '\x56'),
+    ('\x77', '\x57'),
+    ('\x78', '\x58'),
+    ('\x79', '\x59'),
+    ('\x7a', '\x5a'),
+    ('\u00B5', '\u039C'),
+    ('\u00B5', '\u03BC'),
+    ('\u00C0', '\u00E0'),
+    ('\u00C1', '\u00E1'),
+    ('\u00C2', '\u00E2'),
+    ('\u00C3', '\u00E3'),
+    ('\u00C4', '\u00E4'),
+    ('\u00C5', '\u00E5'),
+    ('\u00C5', '\u212B'),
+    ('\u00C6', '\u00E6'),
+    ('\u00C7', '\u00E7'),
+    ('\u00C8', '\u00E8'),
+    ('\u00C9', '\u00E9'),
+    ('\u00CA', '\u00EA'),
+    ('\u00CB', '\u00EB'),
+    ('\u00CC', '\u00EC'),
+    ('\u00CD', '\u00ED'),
+    ('\u00CE', '\u00EE'),
+    ('\u00CF', '\u00EF'),
+    ('\u00D0', '\u00F0'),
+    ('\u00D1', '\u00F1'),
+    ('\u00D2', '\u00F2'),
+    ('\u00D3', '\u00F3'),
+    ('\u00D4', '\u00F4'),
+    ('\u00D5', '\u00F5'),
+    ('\u00D6', '\u00F6'),
+    ('\u00D8', '\u00F8'),
+    ('\u00D9', '\u00F9'),
+    ('\u00DA', '\u00FA'),
+    ('\u00DB', '\u00FB'),
+    ('\u00DC', '\u00FC'),
+    ('\u00DD', '\u00FD'),
+    ('\u00DE', '\u00FE'),
+    ('\u00DF', '\u1E9E'),
+    ('\u00E0', '\u00C0'),
+    ('\u00E1', '\u00C1'),
+    ('\u00E2', '\u00C2'),
+    ('\u00E3', '\u00C3'),
+    ('\u00E4', '\u00C4'),
+    ('\u00E5', '\u00C5'),
+    ('\u00E5', '\u212B'),
+    ('\u00E6', '\u00C6'),
+    ('\u00E7', '\u00C7'),
+    ('\u00E8', '\u00C8'),
+    ('\u00E9', '\u00C9'),
+    ('\u00EA', '\u00CA'),
+    ('\u00EB', '\u00CB'),
+    ('\u00EC', '\u00CC'),
+    ('\u00ED', '\u00CD'),
+    ('\u00EE', '\u00CE'),
+    ('\u00EF', '\u00CF'),
+    ('\u00F0', '\u00D0'),
+    ('\u00F1', '\u00D1'),
+    ('\u00F2', '\u00D2'),
+    ('\u00F3', '\u00D3'),
+    ('\u00F4', '\u00D4'),
+    ('\u00F5', '\u00D5'),
+    ('\u00F6', '\u00D6'),
+    ('\u00F8', '\u00D8'),
+    ('\u00F9', '\u00D9'),
+    ('\u00FA', '\u00DA'),
+    ('\u00FB', '\u00DB'),
+    ('\u00FC', '\u00DC'),
+    ('\u00FD', '\u00DD'),
+    ('\u00FE', '\u00DE'),
+    ('\u00FF', '\u0178'),
+    ('\u0100', '\u0101'),
+    ('\u0101', '\u0100'),
+    ('\u0102', '\u0103'),
+    ('\u0103', '\u0102'),
+    ('\u0104', '\u0105'),
+    ('\u0105', '\u0104'),
+    ('\u0106', '\u0107'),
+    ('\u0107', '\u0106'),
+    ('\u0108', '\u0109'),
+    ('\u0109', '\u0108'),
+    ('\u010A', '\u010B'),
+    ('\u010B', '\u010A'),
+    ('\u010C', '\u010D'),
+    ('\u010D', '\u010C'),
+    ('\u010E', '\u010F'),
+    ('\u010F', '\u010E'),
+    ('\u0110', '\u0111'),
+    ('\u0111', '\u0110'),
+    ('\u0112', '\u0113'),
+    ('\u0113', '\u0112'),
+    ('\u0114', '\u0115'),
+    ('\u0115', '\u0114'),
+    ('\u0116', '\u0117'),
+    ('\u0117', '\u0116'),
+    ('\u0118', '\u0119'),
+    ('\u0119', '\u0118'),
+    ('\u011A', '\u011B'),
+    ('\u011B', '\u011A'),
+    ('\u011C', '\u011D'),
+    ('\u011D', '\u011C'),
+    ('\u011E', '\u011F'),
+    ('\u011F', '\u011E'),
+    ('\u0120', '\u0121'),
+    ('\u0121', '\u0120'),
+    ('\u0122', '\u0123'),
+    ('\u0123', '\u0122'),
+    ('\u0124', '\u0125'),
+    ('\u0125', '\u0124'),
+    ('\u0126', '\u0127'),
+    ('\u0127', '\u0126'),
+    ('\u0128', '\u0129'),
+    ('\u0129', '\u0128'),
+    ('\u012A', '\u012B'),
+    ('\u012B', '\u012A'),
+    ('\u012C', '\u012D'),
+    ('\u012D', '\u012C'),
+    ('\u012E', '\u012F'),
+    ('\u012F', '\u012E'),
+    ('\u0132', '\u0133'),
+    ('\u0133', '\u0132'),
+    ('\u0134', '\u0135'),
+    ('\u0135', '\u0134'),
+    ('\u0136', '\u0137'),
+    ('\u0137', '\u0136'),
+    ('\u0139', '\u013A'),
+    ('\u013A', '\u0139'),
+    ('\u013B', '\u013C'),
+    ('\u013C', '\u013B'),
+    ('\u013D', '\u013E'),
+    ('\u013E', '\u013D'),
+    ('\u013F', '\u0140'),
+    ('\u0140', '\u013F'),
+    ('\u0141', '\u0142'),
+    ('\u0142', '\u0141'),
+    ('\u0143', '\u0144'),
+    ('\u0144', '\u0143'),
+    ('\u0145', '\u0146'),
+    ('\u0146', '\u0145'),
+    ('\u0147', '\u0148'),
+    ('\u0148', '\u0147'),
+    ('\u014A', '\u014B'),
+    ('\u014B', '\u014A'),
+    ('\u014C', '\u014D'),
+    ('\u014D', '\u014C'),
+    ('\u014E', '\u014F'),
+    ('\u014F', '\u014E'),
+    ('\u0150', '\u0151'),
+    ('\u0151', '\u0150'),
+    ('\u0152', '\u0153'),
+    ('\u0153', '\u0152'),
+    ('\u0154', '\u0155'),
+    ('\u0155', '\u0154'),
+    ('\u0156', '\u0157'),
+    ('\u0157', '\u0156'),
+    ('\u0158', '\u0159'),
+    ('\u0159', '\u0158'),
+    ('\u015A', '\u015B'),
+    ('\u015B', '\u015A'),
+    ('\u015C', '\u015D'),
+    ('\u015D', '\u015C'),
+    ('\u015E', '\u015F'),
+    ('\u015F', '\u015E'),
+    ('\u0160', '\u0161'),
+    ('\u0161', '\u0160'),
+    ('\u0162', '\u0163'),
+    ('\u0163', '\u0162'),
+    ('\u0164', '\u0165'),
+    ('\u0165', '\u0164'),
+    ('\u0166', '\u0167'),
+    ('\u0167', '\u0166'),
+    ('\u0168', '\u0169'),
+    ('\u0169', '\u0168'),
+    ('\u016A', '\u016B'),
+    ('\u016B', '\u016A'),
+    ('\u016C', '\u016D'),
+    ('\u016D', '\u016C'),
+    ('\u016E', '\u016F'),
+    ('\u016F', '\u016E'),
+    ('\u0170', '\u0171'),
+    ('\u0171', '\u0170'),
+    ('\u0172', '\u0173'),
+    ('\u0173', '\u0172'),
+    ('\u0174', '\u0175'),
+    ('\u0175', '\u0174'),
+    ('\u0176', '\u0177'),
+    ('\u0177', '\u0176'),
+    ('\u0178', '\u00FF'),
+    ('\u0179', '\u017A'),
+    ('\u017A', '\u0179'),
+    ('\u017B', '\u017C'),
+    ('\u017C', '\u017B'),
+    ('\u017D', '\u017E'),
+    ('\u017E', '\u017D'),
+    ('\u017F', '\x53'),
+    ('\u017F', '\x73'),
+    ('\u0180', '\u0243'),
+    ('\u0181', '\u0253'),
+    ('\u0182', '\u0183'),
+    ('\u0183', '\u0182'),
+    ('\u0184', '\u0185'),
+    ('\u0185', '\u0184'),
+    ('\u0186', '\u0254'),
+    ('\u0187', '\u0188'),
+    ('\u0188', '\u0187'),
+    ('\u0189', '\u0256'),
+    ('\u018A', '\u0257'),
+    ('\u018B', '\u018C'),
+    ('\u018C', '\u018B'),
+    ('\u018E', '\u01DD'),
+    ('\u018F', '\u0259'),
+    ('\u0190', '\u025B'),
+    ('\u0191', '\u0192'),
+    ('\u0192', '\u0191'),
+    ('\u0193', '\u0260'),
+    ('\u0194', '\u0263'),
+    ('\u0195', '\u01F6'),
+    ('\u0196', '\u0269'),
+    ('\u0197', '\u0268'),
+    ('\u0198', '\u0199'),
+    ('\u0199', '\u0198'),
+    ('\u019A', '\u023D'),
+    ('\u019C', '\u026F'),
+    ('\u019D', '\u0272'),
+    ('\u019E', '\u0220'),
+    ('\u019F', '\u0275'),
+    ('\u01A0', '\u01A1'),
+    ('\u01A1', '\u01A0'),
+    ('\u01A2', '\u01A3'),
+    ('\u01A3', '\u01A2'),
+    ('\u01A4', '\u01A5'),
+    ('\u01A5', '\u01A4'),
+    ('\u01A6', '\u0280'),
+    ('\u01A7', '\u01A8'),
+    ('\u01A8', '\u01A7'),
+    ('\u01A9', '\u0283'),
+    ('\u01AC', '\u01AD'),
+    ('\u01AD', '\u01AC'),
+    ('\u01AE', '\u0288'),
+    ('\u01AF', '\u01B0'),
+    ('\u01B0', '\u01AF'),
+    ('\u01B1', '\u028A'),
+    ('\u01B2', '\u028B'),
+    ('\u01B3', '\u01B4'),
+    ('\u01B4', '\u01B3'),
+    ('\u01B5', '\u01B6'),
+    ('\u01B6', '\u01B5'),
+    ('\u01B7', '\u0292'),
+    ('\u01B8', '\u01B9'),
+    ('\u01B9', '\u01B8'),
+    ('\u01BC', '\u01BD'),
+    ('\u01BD', '\u01BC'),
+    ('\u01BF', '\u01F7'),
+    ('\u01C4', '\u01C5'),
+    ('\u01C4', '\u01C6'),
+    ('\u01C5', '\u01C4'),
+    ('\u01C5', '\u01C6'),
+    ('\u01C6', '\u01C4'),
+    ('\u01C6', '\u01C5'),
+    ('\u01C7', '\u01C8'),
+    ('\u01C7', '\u01C9'),
+    ('\u01C8', '\u01C7'),
+    ('\u01C8', '\u01C9'),
+    ('\u01C9', '\u01C7'),
+    ('\u01C9', '\u01C8'),
+    ('\u01CA', '\u01CB'),
+    ('\u01CA', '\u01CC'),
+    ('\u01CB', '\u01CA'),
+    ('\u01CB', '\u01CC'),
+    ('\u01CC', '\u01CA'),
+    ('\u01CC', '\u01CB'),
+    ('\u01CD', '\u01CE'),
+    ('\u01CE', '\u01CD'),
+    ('\u01CF', '\u01D0'),
+    ('\u01D0', '\u01CF'),
+    ('\u01D1', '\u01D2'),
+    ('\u01D2', '\u01D1'),
+    ('\u01D3', '\u01D4'),
+    ('\u01D4', '\u01D3'),
+    ('\u01D5', '\u01D6'),
+    ('\u01D6', '\u01D5'),
+    ('\u01D7', '\u01D8'),
+    ('\u01D8', '\u01D7'),
+    ('\u01D9', '\u01DA'),
+    ('\u01DA', '\u01D9'),
+    ('\u01DB', '\u01DC'),
+    ('\u01DC', '\u01DB'),
+    ('\u01DD', '\u018E'),
+    ('\u01DE', '\u01DF'),
+    ('\u01DF', '\u01DE'),
+    ('\u01E0', '\u01E1'),
+    ('\u01E1', '\u01E0'),
+    ('\u01E2', '\u01E3'),
+    ('\u01E3', '\u01E2'),
+    ('\u01E4', '\u01E5'),
+    ('\u01E5', '\u01E4'),
+    ('\u01E6', '\u01E7'),
+    ('\u01E7', '\u01E6'),
+    ('\u01E8', '\u01E9'),
+    ('\u01E9', '\u01E8'),
+    ('\u01EA', '\u01EB'),
+    ('\u01EB', '\u01EA'),
+    ('\u01EC', '\u01ED'),
+    ('\u01ED', '\u01EC'),
+    ('\u01EE', '\u01EF'),
+    ('\u01EF', '\u01EE'),
+    ('\u01F1', '\u01F2'),
+    ('\u01F1', '\u01F3'),
+    ('\u01F2', '\u01F1'),
+    ('\u01F2', '\u01F3'),
+    ('\u01F3', '\u01F1'),
+    ('\u01F3', '\u01F2'),
+    ('\u01F4', '\u01F5'),
+    ('\u01F5', '\u01F4'),
+    ('\u01F6', '\u0195'),
+    ('\u01F7', '\u01BF'),
+    ('\u01F8', '\u01F9'),
+    ('\u01F9', '\u01F8'),
+    ('\u01FA', '\u01FB'),
+    ('\u01FB', '\u01FA'),
+    ('\u01FC', '\u01FD'),
+    ('\u01FD', '\u01FC'),
+    ('\u01FE', '\u01FF'),
+    ('\u01FF', '\u01FE'),
+    ('\u0200', '\u0201'),
+    ('\u0201', '\u0200'),
+    ('\u0202', '\u0203'),
+    ('\u0203', '\u0202'),
+    ('\u0204', '\u0205'),
+    ('\u0205', '\u0204'),
+    ('\u0206', '\u0207'),
+    ('\u0207', '\u0206'),
+    ('\u0208', '\u0209'),
+    ('\u0209', '\u0208'),
+    ('\u020A', '\u020B'),
+    ('\u020B', '\u020A'),
+    ('\u020C', '\u020D'),
+    ('\u020D', '\u020C'),
+    ('\u020E', '\u020F'),
+    ('\u020F', '\u020E'),
+    ('\u0210', '\u0211'),
+    ('\u0211', '\u0210'),
+    ('\u0212', '\u0213'),
+    ('\u0213', '\u0212'),
+    ('\u0214', '\u0215'),
+    ('\u0215', '\u0214'),
+    ('\u0216', '\u0217'),
+    ('\u0217', '\u0216'),
+    ('\u0218', '\u0219'),
+    ('\u0219', '\u0218'),
+    ('\u021A', '\u021B'),
+    ('\u021B', '\u021A'),
+    ('\u021C', '\u021D'),
+    ('\u021D', '\u021C'),
+    ('\u021E', '\u021F'),
+    ('\u021F', '\u021E'),
+    ('\u0220', '\u019E'),
+    ('\u0222', '\u0223'),
+    ('\u0223', '\u0222'),
+    ('\u0224', '\u0225'),
+    ('\u0225', '\u0224'),
+    ('\u0226', '\u0227'),
+    ('\u0227', '\u0226'),
+    ('\u0228', '\u0229'),
+    ('\u0229', '\u0228'),
+    ('\u022A', '\u022B'),
+    ('\u022B', '\u022A'),
+    ('\u022C', '\u022D'),
+    ('\u022D', '\u022C'),
+    ('\u022E', '\u022F'),
+    ('\u022F', '\u022E'),
+    ('\u0230', '\u0231'),
+    ('\u0231', '\u0230'),
+    ('\u0232', '\u0233'),
+    ('\u0233', '\u0232'),
+    ('\u023A', '\u2C65'),
+    ('\u023B', '\u023C'),
+    ('\u023C', '\u023B'),
+    ('\u023D', '\u019A'),
+    ('\u023E', '\u2C66'),
+    ('\u023F', '\u2C7E'),
+    ('\u0240', '\u2C7F'),
+    ('\u0241', '\u0242'),
+    ('\u0242', '\u0241'),
+    ('\u0243', '\u0180'),
+    ('\u0244', '\u0289'),
+    ('\u0245', '\u028C'),
+    ('\u0246', '\u0247'),
+    ('\u0247', '\u0246'),
+    ('\u0248', '\u0249'),
+    ('\u0249', '\u0248'),
+    ('\u024A', '\u024B'),
+    ('\u024B', '\u024A'),
+    ('\u024C', '\u024D'),
+    ('\u024D', '\u024C'),
+    ('\u024E', '\u024F'),
+    ('\u024F', '\u024E'),
+    ('\u0250', '\u2C6F'),
+    ('\u0251', '\u2C6D'),
+    ('\u0252', '\u2C70'),
+    ('\u0253', '\u0181'),
+    ('\u0254', '\u0186'),
+    ('\u0256', '\u0189'),
+    ('\u0257', '\u018A'),
+    ('\u0259', '\u018F'),
+    ('\u025B', '\u0190'),
+    ('\u025C', '\uA7AB'),
+    ('\u0260', '\u0193'),
+    ('\u0261', '\uA7AC'),
+    ('\u0263', '\u0194'),
+    ('\u0265', '\uA78D'),
+    ('\u0266', '\uA7AA'),
+    ('\u0268', '\u0197'),
+    ('\u0269', '\u0196'),
+    ('\u026A', '\uA7AE'),
+    ('\u026B', '\u2C62'),
+    ('\u026C', '\uA7AD'),
+    ('\u026F', '\u019C'),
+    ('\u0271', '\u2C6E'),
+    ('\u0272', '\u019D'),
+    ('\u0275', '\u019F'),
+    ('\u027D', '\u2C64'),
+    ('\u0280', '\u01A6'),
+    ('\u0282', '\uA7C5'),
+    ('\u0283', '\u01A9'),
+    ('\u0287', '\uA7B1'),
+    ('\u0288', '\u01AE'),
+    ('\u0289', '\u0244'),
+    ('\u028A', '\u01B1'),
+    ('\u028B', '\u01B2'),
+    ('\u028C', '\u0245'),
+    ('\u0292', '\u01B7'),
+    ('\u029D', '\uA7B2'),
+    ('\u029E', '\uA7B0'),
+    ('\u0345', '\u0399'),
+    ('\u0345', '\u03B9'),
+    ('\u0345', '\u1FBE'),
+    ('\u0370', '\u0371'),
+    ('\u0371', '\u0370'),
+    ('\u0372', '\u0373'),
+    ('\u0373', '\u0372'),
+    ('\u0376', '\u0377'),
+    ('\u0377', '\u0376'),
+    ('\u037B', '\u03FD'),
+    ('\u037C', '\u03FE'),
+    ('\u037D', '\u03FF'),
+    ('\u037F', '\u03F3'),
+    ('\u0386', '\u03AC'),
+    ('\u0388', '\u03AD'),
+    ('\u0389', '\u03AE'),
+    ('\u038A', '\u03AF'),
+    ('\u038C', '\u03CC'),
+    ('\u038E', '\u03CD'),
+    ('\u038F', '\u03CE'),
+    ('\u0391', '\u03B1'),
+    ('\u0392', '\u03B2'),
+    ('\u0392', '\u03D0'),
+    ('\u0393', '\u03B3'),
+    ('\u0394', '\u03B4'),
+    ('\u0395', '\u03B5'),
+    ('\u0395', '\u03F5'),
+    ('\u0396', '\u03B6'),
+    ('\u0397', '\u03B7'),
+    ('\u0398', '\u03B8'),
+    ('\u0398', '\u03D1'),
+    ('\u0398', '\u03F4'),
+    ('\u0399', '\u0345'),
+    ('\u0399', '\u03B9'),
+    ('\u0399', '\u1FBE'),
+    ('\u039A', '\u03BA'),
+    ('\u039A', '\u03F0'),
+    ('\u039B', '\u03BB'),
+    ('\u039C', '\u00B5'),
+    ('\u039C', '\u03BC'),
+    ('\u039D', '\u03BD'),
+    ('\u039E', '\u03BE'),
+    ('\u039F', '\u03BF'),
+    ('\u03A0', '\u03C0'),
+    ('\u03A0', '\u03D6'),
+    ('\u03A1', '\u03C1'),
+    ('\u03A1', '\u03F1'),
+    ('\u03A3', '\u03C2'),
+    ('\u03A3', '\u03C3'),
+    ('\u03A4', '\u03C4'),
+    ('\u03A5', '\u03C5'),
+    ('\u03A6', '\u03C6'),
+    ('\u03A6', '\u03D5'),
+    ('\u03A7', '\u03C7'),
+    ('\u03A8', '\u03C8'),
+    ('\u03A9', '\u03C9'),
+    ('\u03A9', '\u2126'),
+    ('\u03AA', '\u03CA'),
+    ('\u03AB', '\u03CB'),
+    ('\u03AC', '\u0386'),
+    ('\u03AD', '\u0388'),
+    ('\u03AE', '\u0389'),
+    ('\u03AF', '\u038A'),
+    ('\u03B1', '\u0391'),
+    ('\u03B2', '\u0392'),
+    ('\u03B2', '\u03D0'),
+    ('\u03B3', '\u0393'),
+    ('\u03B4', '\u0394'),
+    ('\u03B5', '\u0395'),
+    ('\u03B5', '\u03F5'),
+    ('\u03B6', '\u0396'),
+    ('\u03B7', '\u0397'),
+    ('\u03B8', '\u0398'),
+    ('\u03B8', '\u03D1'),
+    ('\u03B8', '\u03F4'),
+    ('\u03B9', '\u0345'),
+    ('\u03B9', '\u0399'),
+    ('\u03B9', '\u1FBE'),
+    ('\u03BA', '\u039A'),
+    ('\u03BA', '\u03F0'),
+    ('\u03BB', '\u039B'),
+    ('\u03BC', '\u00B5'),
+    ('\u03BC', '\u039C'),
+    ('\u03BD', '\u039D'),
+    ('\u03BE', '\u039E'),
+    ('\u03BF', '\u039F'),
+    ('\u03C0', '\u03A0'),
+    ('\u03C0', '\u03D6'),
+    ('\u03C1', '\u03A1'),
+    ('\u03C1', '\u03F1'),
+    ('\u03C2', '\u03A3'),
+    ('\u03C2', '\u03C3'),
+    ('\u03C3', '\u03A3'),
+    ('\u03C3', '\u03C2'),
+    ('\u03C4', '\u03A4'),
+    ('\u03C5', '\u03A5'),
+    ('\u03C6', '\u03A6'),
+    ('\u03C6', '\u03D5'),
+    ('\u03C7', '\u03A7'),
+    ('\u03C8', '\u03A8'),
+    ('\u03C9', '\u03A9'),
+    ('\u03C9', '\u2126'),
+    ('\u03CA', '\u03AA'),
+    ('\u03CB', '\u03AB'),
+    ('\u03CC', '\u038C'),
+    ('\u03CD', '\u038E'),
+    ('\u03CE', '\u038F'),
+    ('\u03CF', '\u03D7'),
+    ('\u03D0', '\u0392'),
+    ('\u03D0', '\u03B2'),
+    ('\u03D1', '\u0398'),
+    ('\u03D1', '\u03B8'),
+    ('\u03D1', '\u03F4'),
+    ('\u03D5', '\u03A6'),
+    ('\u03D5', '\u03C6'),
+    ('\u03D6', '\u03A0'),
+    ('\u03D6', '\u03C0'),
+    ('\u03D7', '\u03CF'),
+    ('\u03D8', '\u03D9'),
+    ('\u03D9', '\u03D8'),
+    ('\u03DA', '\u03DB'),
+    ('\u03DB', '\u03DA'),
+    ('\u03DC', '\u03DD'),
+    ('\u03DD', '\u03DC'),
+    ('\u03DE', '\u03DF'),
+    ('\u03DF', '\u03DE'),
+    ('\u03E0', '\u03E1'),
+    ('\u03E1', '\u03E0'),
+    ('\u03E2', '\u03E3'),
+    ('\u03E3', '\u03E2'),
+    ('\u03E4', '\u03E5'),
+    ('\u03E5', '\u03E4'),
+    ('\u03E6', '\u03E7'),
+    ('\u03E7', '\u03E6'),
+    ('\u03E8', '\u03E9'),
+    ('\u03E9', '\u03E8'),
+    ('\u03EA', '\u03EB'),
+    ('\u03EB', '\u03EA'),
+    ('\u03EC', '\u03ED'),
+    ('\u03ED', '\u03EC'),
+    ('\u03EE', '\u03EF'),
+    ('\u03EF', '\u03EE'),
+    ('\u03F0', '\u039A'),
+    ('\u03F0', '\u03BA'),
+    ('\u03F1', '\u03A1'),
+    ('\u03F1', '\u03C1'),
+    ('\u03F2', '\u03F9'),
+    ('\u03F3', '\u037F'),
+    ('\u03F4', '\u0398'),
+    ('\u03F4', '\u03B8'),
+    ('\u03F4', '\u03D1'),
+    ('\u03F5', '\u0395'),
+    ('\u03F5', '\u03B5'),
+    ('\u03F7', '\u03F8'),
+    ('\u03F8', '\u03F7'),
+    ('\u03F9', '\u03F2'),
+    ('\u03FA', '\u03FB'),
+    ('\u03FB', '\u03FA'),
+    ('\u03FD', '\u037B'),
+    ('\u03FE', '\u037C'),
+    ('\u03FF', '\u037D'),
+    ('\u0400', '\u0450'),
+    ('\u0401', '\u0451'),
+    ('\u0402', '\u0452'),
+    ('\u0403', '\u0453'),
+    ('\u0404', '\u0454'),
+    ('\u0405', '\u0455'),
+    ('\u0406', '\u0456'),
+    ('\u0407', '\u0457'),
+    ('\u0408', '\u0458'),
+    ('\u0409', '\u0459'),
+    ('\u040A', '\u045A'),
+    ('\u040B', '\u045B'),
+    ('\u040C', '\u045C'),
+    ('\u040D', '\u045D'),
+    ('\u040E', '\u045E'),
+    ('\u040F', '\u045F'),
+    ('\u0410', '\u0430'),
+    ('\u0411', '\u0431'),
+    ('\u0412', '\u0432'),
+    ('\u0412', '\u1C80'),
+    ('\u0413', '\u0433'),
+    ('\u0414', '\u0434'),
+    ('\u0414', '\u1C81'),
+    ('\u0415', '\u0435'),
+    ('\u0416', '\u0436'),
+    ('\u0417', '\u0437'),
+    ('\u0418', '\u0438'),
+    ('\u0419', '\u0439'),
+    ('\u041A', '\u043A'),
+    ('\u041B', '\u043B'),
+    ('\u041C', '\u043C'),
+    ('\u041D', '\u043D'),
+    ('\u041E', '\u043E'),
+    ('\u041E', '\u1C82'),
+    ('\u041F', '\u043F'),
+    ('\u0420', '\u0440'),
+    ('\u0421', '\u0441'),
+    ('\u0421', '\u1C83'),
+    ('\u0422', '\u0442'),
+    ('\u0422', '\u1C84'),
+    ('\u0422', '\u1C85'),
+    ('\u0423', '\u0443'),
+    ('\u0424', '\u0444'),
+    ('\u0425', '\u0445'),
+    ('\u0426', '\u0446'),
+    ('\u0427', '\u0447'),
+    ('\u0428', '\u0448'),
+    ('\u0429', '\u0449'),
+    ('\u042A', '\u044A'),
+    ('\u042A', '\u1C86'),
+    ('\u042B', '\u044B'),
+    ('\u042C', '\u044C'),
+    ('\u042D', '\u044D'),
+    ('\u042E', '\u044E'),
+    ('\u042F', '\u044F'),
+    ('\u0430', '\u0410'),
+    ('\u0431', '\u0411'),
+    ('\u0432', '\u0412'),
+    ('\u0432', '\u1C80'),
+    ('\u0433', '\u0413'),
+    ('\u0434', '\u0414'),
+    ('\u0434', '\u1C81'),
+    ('\u0435', '\u0415'),
+    ('\u0436', '\u0416'),
+    ('\u0437', '\u0417'),
+    ('\u0438', '\u0418'),
+    ('\u0439', '\u0419'),
+    ('\u043A', '\u041A'),
+    ('\u043B', '\u041B'),
+    ('\u043C', '\u041C'),
+    ('\u043D', '\u041D'),
+    ('\u043E', '\u041E'),
+    ('\u043E', '\u1C82'),
+    ('\u043F', '\u041F'),
+    ('\u0440', '\u0420'),
+    ('\u0441', '\u0421'),
+    ('\u0441', '\u1C83'),
+    ('\u0442', '\u0422'),
+    ('\u0442', '\u1C84'),
+    ('\u0442', '\u1C85'),
+    ('\u0443', '\u0423'),
+    ('\u0444', '\u0424'),
+    ('\u0445', '\u0425'),
+    ('\u0446', '\u0426'),
+    ('\u0447', '\u0427'),
+    ('\u0448', '\u0428'),
+    ('\u0449', '\u0429'),
+    ('\u044A', '\u042A'),
+    ('\u044A', '\u1C86'),
+    ('\u044B', '\u042B'),
+    ('\u044C', '\u042C'),
+    ('\u044D', '\u042D'),
+    ('\u044E', '\u042E'),
+    ('\u044F', '\u042F'),
+    ('\u0450', '\u0400'),
+    ('\u0451', '\u0401'),
+    ('\u0452', '\u0402'),
+    ('\u0453', '\u0403'),
+    ('\u0454', '\u0404'),
+    ('\u0455', '\u0405'),
+    ('\u0456', '\u0406'),
+    ('\u0457', '\u0407'),
+    ('\u0458', '\u0408'),
+    ('\u0459', '\u0409'),
+    ('\u045A', '\u040A'),
+    ('\u045B', '\u040B'),
+    ('\u045C', '\u040C'),
+    ('\u045D', '\u040D'),
+    ('\u045E', '\u040E'),
+    ('\u045F', '\u040F'),
+    ('\u0460', '\u0461'),
+    ('\u0461', '\u0460'),
+    ('\u0462', '\u0463'),
+    ('\u0462', '\u1C87'),
+    ('\u0463', '\u0462'),
+    ('\u0463', '\u1C87'),
+    ('\u0464', '\u0465'),
+    ('\u0465', '\u0464'),
+    ('\u0466', '\u0467'),
+    ('\u0467', '\u0466'),
+    ('\u0468', '\u0469'),
+    ('\u0469', '\u0468'),
+    ('\u046A', '\u046B'),
+    ('\u046B', '\u046A'),
+    ('\u046C', '\u046D'),
+    ('\u046D', '\u046C'),
+    ('\u046E', '\u046F'),
+    ('\u046F', '\u046E'),
+    ('\u0470', '\u0471'),
+    ('\u0471', '\u0470'),
+    ('\u0472', '\u0473'),
+    ('\u0473', '\u0472'),
+    ('\u0474', '\u0475'),
+    ('\u0475', '\u0474'),
+    ('\u0476', '\u0477'),
+    ('\u0477', '\u0476'),
+    ('\u0478', '\u0479'),
+    ('\u0479', '\u0478'),
+    ('\u047A', '\u047B'),
+    ('\u047B', '\u047A'),
+    ('\u047C', '\u047D'),
+    ('\u047D', '\u047C'),
+    ('\u047E', '\u047F'),
+    ('\u047F', '\u047E'),
+    ('\u0480', '\u0481'),
+    ('\u0481', '\u0480'),
+    ('\u048A', '\u048B'),
+    ('\u048B', '\u048A'),
+    ('\u048C', '\u048D'),
+    ('\u048D', '\u048C'),
+    ('\u048E', '\u048F'),
+    ('\u048F', '\u048E'),
+    ('\u0490', '\u0491'),
+    ('\u0491', '\u0490'),
+    ('\u0492', '\u0493'),
+    ('\u0493', '\u0492'),
+    ('\u0494', '\u0495'),
+    ('\u0495', '\u0494'),
+    ('\u0496', '\u0497'),
+    ('\u0497', '\u0496'),
+    ('\u0498', '\u0499'),
+    ('\u0499', '\u0498'),
+    ('\u049A', '\u049B'),
+    ('\u049B', '\u049A'),
+    ('\u049C', '\u049D'),
+    ('\u049D', '\u049C'),
+    ('\u049E', '\u049F'),
+    ('\u049F', '\u049E'),
+    ('\u04A0', '\u04A1'),
+    ('\u04A1', '\u04A0'),
+    ('\u04A2', '\u04A3'),
+    ('\u04A3', '\u04A2'),
+    ('\u04A4', '\u04A5'),
+    ('\u04A5', '\u04A4'),
+    ('\u04A6', '\u04A7'),
+    ('\u04A7', '\u04A6'),
+    ('\u04A8', '\u04A9'),
+    ('\u04A9', '\u04A8'),
+    ('\u04AA', '\u04AB'),
+    ('\u04AB', '\u04AA'),
+    ('\u04AC', '\u04AD'),
+    ('\u04AD', '\u04AC'),
+    ('\u04AE', '\u04AF'),
+    ('\u04AF', '\u04AE'),
+    ('\u04B0', '\u04B1'),
+    ('\u04B1', '\u04B0'),
+    ('\u04B2', '\u04B3'),
+    ('\u04B3', '\u04B2'),
+    ('\u04B4', '\u04B5'),
+    ('\u04B5', '\u04B4'),
+    ('\u04B6', '\u04B7'),
+    ('\u04B7', '\u04B6'),
+    ('\u04B8', '\u04B9'),
+    ('\u04B9', '\u04B8'),
+    ('\u04BA', '\u04BB'),
+    ('\u04BB', '\u04BA'),
+    ('\u04BC', '\u04BD'),
+    ('\u04BD', '\u04BC'),
+    ('\u04BE', '\u04BF'),
+    ('\u04BF', '\u04BE'),
+    ('\u04C0', '\u04CF'),
+    ('\u04C1', '\u04C2'),
+    ('\u04C2', '\u04C1'),
+    ('\u04C3', '\u04C4'),
+    ('\u04C4', '\u04C3'),
+    ('\u04C5', '\u04C6'),
+    ('\u04C6', '\u04C5'),
+    ('\u04C7', '\u04C8'),
+    ('\u04C8', '\u04C7'),
+    ('\u04C9', '\u04CA'),
+    ('\u04CA', '\u04C9'),
+    ('\u04CB', '\u04CC'),
+    ('\u04CC', '\u04CB'),
+    ('\u04CD', '\u04CE'),
+    ('\u04CE', '\u04CD'),
+    ('\u04CF', '\u04C0'),
+    ('\u04D0', '\u04D1'),
+    ('\u04D1', '\u04D0'),
+    ('\u04D2', '\u04D3'),
+    ('\u04D3', '\u04D2'),
+    ('\u04D4', '\u04D5'),
+    ('\u04D5', '\u04D4'),
+    ('\u04D6', '\u04D7'),
+    ('\u04D7', '\u04D6'),
+    ('\u04D8', '\u04D9'),
+    ('\u04D9', '\u04D8'),
+    ('\u04DA', '\u04DB'),
+    ('\u04DB', '\u04DA'),
+    ('\u04DC', '\u04DD'),
+    ('\u04DD', '\u04DC'),
+    ('\u04DE', '\u04DF'),
+    ('\u04DF', '\u04DE'),
+    ('\u04E0', '\u04E1'),
+    ('\u04E1', '\u04E0'),
+    ('\u04E2', '\u04E3'),
+    ('\u04E3', '\u04E2'),
+    ('\u04E4', '\u04E5'),
+    ('\u04E5', '\u04E4'),
+    ('\u04E6', '\u04E7'),
+    ('\u04E7', '\u04E6'),
+    ('\u04E8', '\u04E9'),
+    ('\u04E9', '\u04E8'),
+    ('\u04EA', '\u04EB'),
+    ('\u04EB', '\u04EA'),
+    ('\u04EC', '\u04ED'),
+    ('\u04ED', '\u04EC'),
+    ('\u04EE', '\u04EF'),
+    ('\u04EF', '\u04EE'),
+    ('\u04F0', '\u04F1'),
+    ('\u04F1', '\u04F0'),
+    ('\u04F2', '\u04F3'),
+    ('\u04F3', '\u04F2'),
+    ('\u04F4', '\u04F5'),
+    ('\u04F5', '\u04F4'),
+    ('\u04F6', '\u04F7'),
+    ('\u04F7', '\u04F6'),
+    ('\u04F8', '\u04F9'),
+    ('\u04F9', '\u04F8'),
+    ('\u04FA', '\u04FB'),
+    ('\u04FB', '\u04FA'),
+    ('\u04FC', '\u04FD'),
+    ('\u04FD', '\u04FC'),
+    ('\u04FE', '\u04FF'),
+    ('\u04FF', '\u04FE'),
+    ('\u0500', '\u0501'),
+    ('\u0501', '\u0500'),
+    ('\u0502', '\u0503'),
+    ('\u0503', '\u0502'),
+    ('\u0504', '\u0505'),
+    ('\u0505', '\u0504'),
+    ('\u0506', '\u0507'),
+    ('\u0507', '\u0506'),
+    ('\u0508', '\u0509'),
+    ('\u0509', '\u0508'),
+    ('\u050A', '\u050B'),
+    ('\u050B', '\u050A'),
+    ('\u050C', '\u050D'),
+    ('\u050D', '\u050C'),
+    ('\u050E', '\u050F'),
+    ('\u050F', '\u050E'),
+    ('\u0510', '\u0511'),
+    ('\u0511', '\u0510'),
+    ('\u0512', '\u0513'),
+    ('\u0513', '\u0512'),
+    ('\u0514', '\u0515'),
+    ('\u0515', '\u0514'),
+    ('\u0516', '\u0517'),
+    ('\u0517', '\u0516'),
+    ('\u0518', '\u0519'),
+    ('\u0519', '\u0518'),
+    ('\u051A', '\u051B'),
+    ('\u051B', '\u051A'),
+    ('\u051C', '\u051D'),
+    ('\u051D', '\u051C'),
+    ('\u051E', '\u051F'),
+    ('\u051F', '\u051E'),
+    ('\u0520', '\u0521'),
+    ('\u0521', '\u0520'),
+    ('\u0522', '\u0523'),
+    ('\u0523', '\u0522'),
+    ('\u0524', '\u0525'),
+    ('\u0525', '\u0524'),
+    ('\u0526', '\u0527'),
+    ('\u0527', '\u0526'),
+    ('\u0528', '\u0529'),
+    ('\u0529', '\u0528'),
+    ('\u052A', '\u052B'),
+    ('\u052B', '\u052A'),
+    ('\u052C', '\u052D'),
+    ('\u052D', '\u052C'),
+    ('\u052E', '\u052F'),
+    ('\u052F', '\u052E'),
+    ('\u0531', '\u0561'),
+    ('\u0532', '\u0562'),
+    ('\u0533', '\u0563'),
+    ('\u0534', '\u0564'),
+    ('\u0535', '\u0565'),
+    ('\u0536', '\u0566'),
+    ('\u0537', '\u0567'),
+    ('\u0538', '\u0568'),
+    ('\u0539', '\u0569'),
+    ('\u053A', '\u056A'),
+    ('\u053B', '\u056B'),
+    ('\u053C', '\u056C'),
+    ('\u053D', '\u056D'),
+    ('\u053E', '\u056E'),
+    ('\u053F', '\u056F'),
+    ('\u0540', '\u0570'),
+    ('\u0541', '\u0571'),
+    ('\u0542', '\u0572'),
+    ('\u0543', '\u0573'),
+    ('\u0544', '\u0574'),
+    ('\u0545', '\u0575'),
+    ('\u0546', '\u0576'),
+    ('\u0547', '\u0577'),
+    ('\u0548', '\u0578'),
+    ('\u0549', '\u0579'),
+    ('\u054A', '\u057A'),
+    ('\u054B', '\u057B'),
+    ('\u054C', '\u057C'),
+    ('\u054D', '\u057D'),
+    ('\u054E', '\u057E'),
+    ('\u054F', '\u057F'),
+    ('\u0550', '\u0580'),
+    ('\u0551', '\u0581'),
+    ('\u0552', '\u0582'),
+    ('\u0553', '\u0583'),
+    ('\u0554', '\u0584'),
+    ('\u0555', '\u0585'),
+    ('\u0556', '\u0586'),
+    ('\u0561', '\u0531'),
+    ('\u0562', '\u0532'),
+    ('\u0563', '\u0533'),
+    ('\u0564', '\u0534'),
+    ('\u0565', '\u0535'),
+    ('\u0566', '\u0536'),
+    ('\u0567', '\u0537'),
+    ('\u0568', '\u0538'),
+    ('\u0569', '\u0539'),
+    ('\u056A', '\u053A'),
+    ('\u056B', '\u053B'),
+    ('\u056C', '\u053C'),
+    ('\u056D', '\u053D'),
+    ('\u056E', '\u053E'),
+    ('\u056F', '\u053F'),
+    ('\u0570', '\u0540'),
+    ('\u0571', '\u0541'),
+    ('\u0572', '\u0542'),
+    ('\u0573', '\u0543'),
+    ('\u0574', '\u0544'),
+    ('\u0575', '\u0545'),
+    ('\u0576', '\u0546'),
+    ('\u0577', '\u0547'),
+    ('\u0578', '\u0548'),
+    ('\u0579', '\u0549'),
+    ('\u057A', '\u054A'),
+    ('\u057B', '\u054B'),
+    ('\u057C', '\u054C'),
+    ('\u057D', '\u054D'),
+    ('\u057E', '\u054E'),
+    ('\u057F', '\u054F'),
+    ('\u0580', '\u0550'),
+    ('\u0581', '\u0551'),
+    ('\u0582', '\u0552'),
+    ('\u0583', '\u0553'),
+    ('\u0584', '\u0554'),
+    ('\u0585', '\u0555'),
+    ('\u0586', '\u0556'),
+    ('\u10A0', '\u2D00'),
+    ('\u10A1', '\u2D01'),
+    ('\u10A2', '\u2D02'),
+    ('\u10A3', '\u2D03'),
+    ('\u10A4', '\u2D04'),
+    ('\u10A5', '\u2D05'),
+    ('\u10A6', '\u2D06'),
+    ('\u10A7', '\u2D07'),
+    ('\u10A8', '\u2D08'),
+    ('\u10A9', '\u2D09'),
+    ('\u10AA', '\u2D0A'),
+    ('\u10AB', '\u2D0B'),
+    ('\u10AC', '\u2D0C'),
+    ('\u10AD', '\u2D0D'),
+    ('\u10AE', '\u2D0E'),
+    ('\u10AF', '\u2D0F'),
+    ('\u10B0', '\u2D10'),
+    ('\u10B1', '\u2D11'),
+    ('\u10B2', '\u2D12'),
+    ('\u10B3', '\u2D13'),
+    ('\u10B4', '\u2D14'),
+    ('\u10B5', '\u2D15'),
+    ('\u10B6', '\u2D16'),
+    ('\u10B7', '\u2D17'),
+    ('\u10B8', '\u2D18'),
+    ('\u10B9', '\u2D19'),
+    ('\u10BA', '\u2D1A'),
+    ('\u10BB', '\u2D1B'),
+    ('\u10BC', '\u2D1C'),
+    ('\u10BD', '\u2D1D'),
+    ('\u10BE', '\u2D1E'),
+    ('\u10BF', '\u2D1F'),
+    ('\u10C0', '\u2D20'),
+    ('\u10C1', '\u2D21'),
+    ('\u10C2', '\u2D22'),
+    ('\u10C3', '\u2D23'),
+    ('\u10C4', '\u2D24'),
+    ('\u10C5', '\u2D25'),
+    ('\u10C7', '\u2D27'),
+    ('\u10CD', '\u2D2D'),
+    ('\u10D0', '\u1C90'),
+    ('\u10D1', '\u1C91'),
+    ('\u10D2', '\u1C92'),
+    ('\u10D3', '\u1C93'),
+    ('\u10D4', '\u1C94'),
+    ('\u10D5', '\u1C95'),
+    ('\u10D6', '\u1C96'),
+    ('\u10D7', '\u1C97'),
+    ('\u10D8', '\u1C98'),
+    ('\u10D9', '\u1C99'),
+    ('\u10DA', '\u1C9A'),
+    ('\u10DB', '\u1C9B'),
+    ('\u10DC', '\u1C9C'),
+    ('\u10DD', '\u1C9D'),
+    ('\u10DE', '\u1C9E'),
+    ('\u10DF', '\u1C9F'),
+    ('\u10E0', '\u1CA0'),
+    ('\u10E1', '\u1CA1'),
+    ('\u10E2', '\u1CA2'),
+    ('\u10E3', '\u1CA3'),
+    ('\u10E4', '\u1CA4'),
+    ('\u10E5', '\u1CA5'),
+    ('\u10E6', '\u1CA6'),
+    ('\u10E7', '\u1CA7'),
+    ('\u10E8', '\u1CA8'),
+    ('\u10E9', '\u1CA9'),
+    ('\u10EA', '\u1CAA'),
+    ('\u10EB', '\u1CAB'),
+    ('\u10EC', '\u1CAC'),
+    ('\u10ED', '\u1CAD'),
+    ('\u10EE', '\u1CAE'),
+    ('\u10EF', '\u1CAF'),
+    ('\u10F0', '\u1CB0'),
+    ('\u10F1', '\u1CB1'),
+    ('\u10F2', '\u1CB2'),
+    ('\u10F3', '\u1CB3'),
+    ('\u10F4', '\u1CB4'),
+    ('\u10F5', '\u1CB5'),
+    ('\u10F6', '\u1CB6'),
+    ('\u10F7', '\u1CB7'),
+    ('\u10F8', '\u1CB8'),
+    ('\u10F9', '\u1CB9'),
+    ('\u10FA', '\u1CBA'),
+    ('\u10FD', '\u1CBD'),
+    ('\u10FE', '\u1CBE'),
+    ('\u10FF', '\u1CBF'),
+    ('\u13A0', '\uAB70'),
+    ('\u13A1', '\uAB71'),
+    ('\u13A2', '\uAB72'),
+    ('\u13A3', '\uAB73'),
+    ('\u13A4', '\uAB74'),
+    ('\u13A5', '\uAB75'),
+    ('\u13A6', '\uAB76'),
+    ('\u13A7', '\uAB77'),
+    ('\u13A8', '\uAB78'),
+    ('\u13A9', '\uAB79'),
+    ('\u13AA', '\uAB7A'),
+    ('\u13AB', '\uAB7B'),
+    ('\u13AC', '\uAB7C'),
+    ('\u13AD', '\uAB7D'),
+    ('\u13AE', '\uAB7E'),
+    ('\u13AF', '\uAB7F'),
+    ('\u13B0', '\uAB80'),
+    ('\u13B1', '\uAB81'),
+    ('\u13B2', '\uAB82'),
+    ('\u13B3', '\uAB83'),
+    ('\u13B4', '\uAB84'),
+    ('\u13B5', '\uAB85'),
+    ('\u13B6', '\uAB86'),
+    ('\u13B7', '\uAB87'),
+    ('\u13B8', '\uAB88'),
+    ('\u13B9', '\uAB89'),
+    ('\u13BA', '\uAB8A'),
+    ('\u13BB', '\uAB8B'),
+    ('\u13BC', '\uAB8C'),
+    ('\u13BD', '\uAB8D'),
+    ('\u13BE', '\uAB8E'),
+    ('\u13BF', '\uAB8F'),
+    ('\u13C0', '\uAB90'),
+    ('\u13C1', '\uAB91'),
+    ('\u13C2', '\uAB92'),
+    ('\u13C3', '\uAB93'),
+    ('\u13C4', '\uAB94'),
+    ('\u13C5', '\uAB95'),
+    ('\u13C6', '\uAB96'),
+    ('\u13C7', '\uAB97'),
+    ('\u13C8', '\uAB98'),
+    ('\u13C9', '\uAB99'),
+    ('\u13CA', '\uAB9A'),
+    ('\u13CB', '\uAB9B'),
+    ('\u13CC', '\uAB9C'),
+    ('\u13CD', '\uAB9D'),
+    ('\u13CE', '\uAB9E'),
+    ('\u13CF', '\uAB9F'),
+    ('\u13D0', '\uABA0'),
+    ('\u13D1', '\uABA1'),
+    ('\u13D2', '\uABA2'),
+    ('\u13D3', '\uABA3'),
+    ('\u13D4', '\uABA4'),
+    ('\u13D5', '\uABA5'),
+    ('\u13D6', '\uABA6'),
+    ('\u13D7', '\uABA7'),
+    ('\u13D8', '\uABA8'),
+    ('\u13D9', '\uABA9'),
+    ('\u13DA', '\uABAA'),
+    ('\u13DB', '\uABAB'),
+    ('\u13DC', '\uABAC'),
+    ('\u13DD', '\uABAD'),
+    ('\u13DE', '\uABAE'),
+    ('\u13DF', '\uABAF'),
+    ('\u13E0', '\uABB0'),
+    ('\u13E1', '\uABB1'),
+    ('\u13E2', '\uABB2'),
+    ('\u13E3', '\uABB3'),
+    ('\u13E4', '\uABB4'),
+    ('\u13E5', '\uABB5'),
+    ('\u13E6', '\uABB6'),
+    ('\u13E7', '\uABB7'),
+    ('\u13E8', '\uABB8'),
+    ('\u13E9', '\uABB9'),
+    ('\u13EA', '\uABBA'),
+    ('\u13EB', '\uABBB'),
+    ('\u13EC', '\uABBC'),
+    ('\u13ED', '\uABBD'),
+    ('\u13EE', '\uABBE'),
+    ('\u13EF', '\uABBF'),
+    ('\u13F0', '\u13F8'),
+    ('\u13F1', '\u13F9'),
+    ('\u13F2', '\u13FA'),
+    ('\u13F3', '\u13FB'),
+    ('\u13F4', '\u13FC'),
+    ('\u13F5', '\u13FD'),
+    ('\u13F8', '\u13F0'),
+    ('\u13F9', '\u13F1'),
+    ('\u13FA', '\u13F2'),
+    ('\u13FB', '\u13F3'),
+    ('\u13FC', '\u13F4'),
+    ('\u13FD', '\u13F5'),
+    ('\u1C80', '\u0412'),
+    ('\u1C80', '\u0432'),
+    ('\u1C81', '\u0414'),
+    ('\u1C81', '\u0434'),
+    ('\u1C82', '\u041E'),
+    ('\u1C82', '\u043E'),
+    ('\u1C83', '\u0421'),
+    ('\u1C83', '\u0441'),
+    ('\u1C84', '\u0422'),
+    ('\u1C84', '\u0442'),
+    ('\u1C84', '\u1C85'),
+    ('\u1C85', '\u0422'),
+    ('\u1C85', '\u0442'),
+    ('\u1C85', '\u1C84'),
+    ('\u1C86', '\u042A'),
+    ('\u1C86', '\u044A'),
+    ('\u1C87', '\u0462'),
+    ('\u1C87', '\u0463'),
+    ('\u1C88', '\uA64A'),
+    ('\u1C88', '\uA64B'),
+    ('\u1C90', '\u10D0'),
+    ('\u1C91', '\u10D1'),
+    ('\u1C92', '\u10D2'),
+    ('\u1C93', '\u10D3'),
+    ('\u1C94', '\u10D4'),
+    ('\u1C95', '\u10D5'),
+    ('\u1C96', '\u10D6'),
+    ('\u1C97', '\u10D7'),
+    ('\u1C98', '\u10D8'),
+    ('\u1C99', '\u10D9'),
+    ('\u1C9A', '\u10DA'),
+    ('\u1C9B', '\u10DB'),
+    ('\u1C9C', '\u10DC'),
+    ('\u1C9D', '\u10DD'),
+    ('\u1C9E', '\u10DE'),
+    ('\u1C9F', '\u10DF'),
+    ('\u1CA0', '\u10E0'),
+    ('\u1CA1', '\u10E1'),
+    ('\u1CA2', '\u10E2'),
+    ('\u1CA3', '\u10E3'),
+    ('\u1CA4', '\u10E4'),
+    ('\u1CA5', '\u10E5'),
+    ('\u1CA6', '\u10E6'),
+    ('\u1CA7', '\u10E7'),
+    ('\u1CA8', '\u10E8'),
+    ('\u1CA9', '\u10E9'),
+    ('\u1CAA', '\u10EA'),
+    ('\u1CAB', '\u10EB'),
+    ('\u1CAC', '\u10EC'),
+    ('\u1CAD', '\u10ED'),
+    ('\u1CAE', '\u10EE'),
+    ('\u1CAF', '\u10EF'),
+    ('\u1CB0', '\u10F0'),
+    ('\u1CB1', '\u10F1'),
+    ('\u1CB2', '\u10F2'),
+    ('\u1CB3', '\u10F3'),
+    ('\u1CB4', '\u10F4'),
+    ('\u1CB5', '\u10F5'),
+    ('\u1CB6', '\u10F6'),
+    ('\u1CB7', '\u10F7'),
+    ('\u1CB8', '\u10F8'),
+    ('\u1CB9', '\u10F9'),
+    ('\u1CBA', '\u10FA'),
+    ('\u1CBD', '\u10FD'),
+    ('\u1CBE', '\u10FE'),
+    ('\u1CBF', '\u10FF'),
+    ('\u1D79', '\uA77D'),
+    ('\u1D7D', '\u2C63'),
+    ('\u1D8E', '\uA7C6'),
+    ('\u1E00', '\u1E01'),
+    ('\u1E01', '\u1E00'),
+    ('\u1E02', '\u1E03'),
+    ('\u1E03', '\u1E02'),
+    ('\u1E04', '\u1E05'),
+    ('\u1E05', '\u1E04'),
+    ('\u1E06', '\u1E07'),
+    ('\u1E07', '\u1E06'),
+    ('\u1E08', '\u1E09'),
+    ('\u1E09', '\u1E08'),
+    ('\u1E0A', '\u1E0B'),
+    ('\u1E0B', '\u1E0A'),
+    ('\u1E0C', '\u1E0D'),
+    ('\u1E0D', '\u1E0C'),
+    ('\u1E0E', '\u1E0F'),
+    ('\u1E0F', '\u1E0E'),
+    ('\u1E10', '\u1E11'),
+    ('\u1E11', '\u1E10'),
+    ('\u1E12', '\u1E13'),
+    ('\u1E13', '\u1E12'),
+    ('\u1E14', '\u1E15'),
+    ('\u1E15', '\u1E14'),
+    ('\u1E16', '\u1E17'),
+    ('\u1E17', '\u1E16'),
+    ('\u1E18', '\u1E19'),
+    ('\u1E19', '\u1E18'),
+    ('\u1E1A', '\u1E1B'),
+    ('\u1E1B', '\u1E1A'),
+    ('\u1E1C', '\u1E1D'),
+    ('\u1E1D', '\u1E1C'),
+    ('\u1E1E', '\u1E1F'),
+    ('\u1E1F', '\u1E1E'),
+    ('\u1E20', '\u1E21'),
+    ('\u1E21', '\u1E20'),
+    ('\u1E22', '\u1E23'),
+    ('\u1E23', '\u1E22'),
+    ('\u1E24', '\u1E25'),
+    ('\u1E25', '\u1E24'),
+    ('\u1E26', '\u1E27'),
+    ('\u1E27', '\u1E26'),
+    ('\u1E28', '\u1E29'),
+    ('\u1E29', '\u1E28'),
+    ('\u1E2A', '\u1E2B'),
+    ('\u1E2B', '\u1E2A'),
+    ('\u1E2C', '\u1E2D'),
+    ('\u1E2D', '\u1E2C'),
+    ('\u1E2E', '\u1E2F'),
+    ('\u1E2F', '\u1E2E'),
+    ('\u1E30', '\u1E31'),
+    ('\u1E31', '\u1E30'),
+    ('\u1E32', '\u1E33'),
+    ('\u1E33', '\u1E32'),
+    ('\u1E34', '\u1E35'),
+    ('\u1E35', '\u1E34'),
+    ('\u1E36', '\u1E37'),
+    ('\u1E37', '\u1E36'),
+    ('\u1E38', '\u1E39'),
+    ('\u1E39', '\u1E38'),
+    ('\u1E3A', '\u1E3B'),
+    ('\u1E3B', '\u1E3A'),
+    ('\u1E3C', '\u1E3D'),
+    ('\u1E3D', '\u1E3C'),
+    ('\u1E3E', '\u1E3F'),
+    ('\u1E3F', '\u1E3E'),
+    ('\u1E40', '\u1E41'),
+    ('\u1E41', '\u1E40'),
+    ('\u1E42', '\u1E43'),
+    ('\u1E43', '\u1E42'),
+    ('\u1E44', '\u1E45'),
+    ('\u1E45', '\u1E44'),
+    ('\u1E46', '\u1E47'),
+    ('\u1E47', '\u1E46'),
+    ('\u1E48', '\u1E49'),
+    ('\u1E49', '\u1E48'),
+    ('\u1E4A', '\u1E4B'),
+    ('\u1E4B', '\u1E4A'),
+    ('\u1E4C', '\u1E4D'),
+    ('\u1E4D', '\u1E4C'),
+    ('\u1E4E', '\u1E4F'),
+    ('\u1E4F', '\u1E4E'),
+    ('\u1E50', '\u1E51'),
+    ('\u1E51', '\u1E50'),
+    ('\u1E52', '\u1E53'),
+    ('\u1E53', '\u1E52'),
+    ('\u1E54', '\u1E55'),
+    ('\u1E55', '\u1E54'),
+    ('\u1E56', '\u1E57'),
+    ('\u1E57', '\u1E56'),
+    ('\u1E58', '\u1E59'),
+    ('\u1E59', '\u1E58'),
+    ('\u1E5A', '\u1E5B'),
+    ('\u1E5B', '\u1E5A'),
+    ('\u1E5C', '\u1E5D'),
+    ('\u1E5D', '\u1E5C'),
+    ('\u1E5E', '\u1E5F'),
+    ('\u1E5F', '\u1E5E'),
+    ('\u1E60', '\u1E61'),
+    ('\u1E60', '\u1E9B'),
+    ('\u1E61', '\u1E60'),
+    ('\u1E61', '\u1E9B'),
+    ('\u1E62', '\u1E63'),
+    ('\u1E63', '\u1E62'),
+    ('\u1E64', '\u1E65'),
+    ('\u1E65', '\u1E64'),
+    ('\u1E66', '\u1E67'),
+    ('\u1E67', '\u1E66'),
+    ('\u1E68', '\u1E69'),
+    ('\u1E69', '\u1E68'),
+    ('\u1E6A', '\u1E6B'),
+    ('\u1E6B', '\u1E6A'),
+    ('\u1E6C', '\u1E6D'),
+    ('\u1E6D', '\u1E6C'),
+    ('\u1E6E', '\u1E6F'),
+    ('\u1E6F', '\u1E6E'),
+    ('\u1E70', '\u1E71'),
+    ('\u1E71', '\u1E70'),
+    ('\u1E72', '\u1E73'),
+    ('\u1E73', '\u1E72'),
+    ('\u1E74', '\u1E75'),
+    ('\u1E75', '\u1E74'),
+    ('\u1E76', '\u1E77'),
+    ('\u1E77', '\u1E76'),
+    ('\u1E78', '\u1E79'),
+    ('\u1E79', '\u1E78'),
+    ('\u1E7A', '\u1E7B'),
+    ('\u1E7B', '\u1E7A'),
+    ('\u1E7C', '\u1E7D'),
+    ('\u1E7D', '\u1E7C'),
+    ('\u1E7E', '\u1E7F'),
+    ('\u1E7F', '\u1E7E'),
+    ('\u1E80', '\u1E81'),
+    ('\u1E81', '\u1E80'),
+    ('\u1E82', '\u1E83'),
+    ('\u1E83', '\u1E82'),
+    ('\u1E84', '\u1E85'),
+    ('\u1E85', '\u1E84'),
+    ('\u1E86', '\u1E87'),
+    ('\u1E87', '\u1E86'),
+    ('\u1E88', '\u1E89'),
+    ('\u1E89', '\u1E88'),
+    ('\u1E8A', '\u1E8B'),
+    ('\u1E8B', '\u1E8A'),
+    ('\u1E8C', '\u1E8D'),
+    ('\u1E8D', '\u1E8C'),
+    ('\u1E8E', '\u1E8F'),
+    ('\u1E8F', '\u1E8E'),
+    ('\u1E90', '\u1E91'),
+    ('\u1E91', '\u1E90'),
+    ('\u1E92', '\u1E93'),
+    ('\u1E93', '\u1E92'),
+    ('\u1E94', '\u1E95'),
+    ('\u1E95', '\u1E94'),
+    ('\u1E9B', '\u1E60'),
+    ('\u1E9B', '\u1E61'),
+    ('\u1E9E', '\u00DF'),
+    ('\u1EA0', '\u1EA1'),
+    ('\u1EA1', '\u1EA0'),
+    ('\u1EA2', '\u1EA3'),
+    ('\u1EA3', '\u1EA2'),
+    ('\u1EA4', '\u1EA5'),
+    ('\u1EA5', '\u1EA4'),
+    ('\u1EA6', '\u1EA7'),
+    ('\u1EA7', '\u1EA6'),
+    ('\u1EA8', '\u1EA9'),
+    ('\u1EA9', '\u1EA8'),
+    ('\u1EAA', '\u1EAB'),
+    ('\u1EAB', '\u1EAA'),
+    ('\u1EAC', '\u1EAD'),
+    ('\u1EAD', '\u1EAC'),
+    ('\u1EAE', '\u1EAF'),
+    ('\u1EAF', '\u1EAE'),
+    ('\u1EB0', '\u1EB1'),
+    ('\u1EB1', '\u1EB0'),
+    ('\u1EB2', '\u1EB3'),
+    ('\u1EB3', '\u1EB2'),
+    ('\u1EB4', '\u1EB5'),
+    ('\u1EB5', '\u1EB4'),
+    ('\u1EB6', '\u1EB7'),
+    ('\u1EB7', '\u1EB6'),
+    ('\u1EB8', '\u1EB9'),
+    ('\u1EB9', '\u1EB8'),
+    ('\u1EBA', '\u1EBB'),
+    ('\u1EBB', '\u1EBA'),
+    ('\u1EBC', '\u1EBD'),
+    ('\u1EBD', '\u1EBC'),
+    ('\u1EBE', '\u1EBF'),
+    ('\u1EBF', '\u1EBE'),
+    ('\u1EC0', '\u1EC1'),
+    ('\u1EC1', '\u1EC0'),
+    ('\u1EC2', '\u1EC3'),
+    ('\u1EC3', '\u1EC2'),
+    ('\u1EC4', '\u1EC5'),
+    ('\u1EC5', '\u1EC4'),
+    ('\u1EC6', '\u1EC7'),
+    ('\u1EC7', '\u1EC6'),
+    ('\u1EC8', '\u1EC9'),
+    ('\u1EC9', '\u1EC8'),
+    ('\u1ECA', '\u1ECB'),
+    ('\u1ECB', '\u1ECA'),
+    ('\u1ECC', '\u1ECD'),
+    ('\u1ECD', '\u1ECC'),
+    ('\u1ECE', '\u1ECF'),
+    ('\u1ECF', '\u1ECE'),
+    ('\u1ED0', '\u1ED1'),
+    ('\u1ED1', '\u1ED0'),
+    ('\u1ED2', '\u1ED3'),
+    ('\u1ED3', '\u1ED2'),
+    ('\u1ED4', '\u1ED5'),
+    ('\u1ED5', '\u1ED4'),
+    ('\u1ED6', '\u1ED7'),
+    ('\u1ED7', '\u1ED6'),
+    ('\u1ED8', '\u1ED9'),
+    ('\u1ED9', '\u1ED8'),
+    ('\u1EDA', '\u1EDB'),
+    ('\u1EDB', '\u1EDA'),
+    ('\u1EDC', '\u1EDD'),
+    ('\u1EDD', '\u1EDC'),
+    ('\u1EDE', '\u1EDF'),
+    ('\u1EDF', '\u1EDE'),
+    ('\u1EE0', '\u1EE1'),
+    ('\u1EE1', '\u1EE0'),
+    ('\u1EE2', '\u1EE3'),
+    ('\u1EE3', '\u1EE2'),
+    ('\u1EE4', '\u1EE5'),
+    ('\u1EE5', '\u1EE4'),
+    ('\u1EE6', '\u1EE7'),
+    ('\u1EE7', '\u1EE6'),
+    ('\u1EE8', '\u1EE9'),
+    ('\u1EE9', '\u1EE8'),
+    ('\u1EEA', '\u1EEB'),
+    ('\u1EEB', '\u1EEA'),
+    ('\u1EEC', '\u1EED'),
+    ('\u1EED', '\u1EEC'),
+    ('\u1EEE', '\u1EEF'),
+    ('\u1EEF', '\u1EEE'),
+    ('\u1EF0', '\u1EF1'),
+    ('\u1EF1', '\u1EF0'),
+    ('\u1EF2', '\u1EF3'),
+    ('\u1EF3', '\u1EF2'),
+    ('\u1EF4', '\u1EF5'),
+    ('\u1EF5', '\u1EF4'),
+    ('\u1EF6', '\u1EF7'),
+    ('\u1EF7', '\u1EF6'),
+    ('\u1EF8', '\u1EF9'),
+    ('\u1EF9', '\u1EF8'),
+    ('\u1EFA', '\u1EFB'),
+    ('\u1EFB', '\u1EFA'),
+    ('\u1EFC', '\u1EFD'),
+    ('\u1EFD', '\u1EFC'),
+    ('\u1EFE', '\u1EFF'),
+    ('\u1EFF', '\u1EFE'),
+    ('\u1F00', '\u1F08'),
+    ('\u1F01', '\u1F09'),
+    ('\u1F02', '\u1F0A'),
+    ('\u1F03', '\u1F0B'),
+    ('\u1F04', '\u1F0C'),
+    ('\u1F05', '\u1F0D'),
+    ('\u1F06', '\u1F0E'),
+    ('\u1F07', '\u1F0F'),
+    ('\u1F08', '\u1F00'),
+    ('\u1F09', '\u1F01'),
+    ('\u1F0A', '\u1F02'),
+    ('\u1F0B', '\u1F03'),
+    ('\u1F0C', '\u1F04'),
+    ('\u1F0D', '\u1F05'),
+    ('\u1F0E', '\u1F06'),
+    ('\u1F0F', '\u1F07'),
+    ('\u1F10', '\u1F18'),
+    ('\u1F11', '\u1F19'),
+    ('\u1F12', '\u1F1A'),
+    ('\u1F13', '\u1F1B'),
+    ('\u1F14', '\u1F1C'),
+    ('\u1F15', '\u1F1D'),
+    ('\u1F18', '\u1F10'),
+    ('\u1F19', '\u1F11'),
+    ('\u1F1A', '\u1F12'),
+    ('\u1F1B', '\u1F13'),
+    ('\u1F1C', '\u1F14'),
+    ('\u1F1D', '\u1F15'),
+    ('\u1F20', '\u1F28'),
+    ('\u1F21', '\u1F29'),
+    ('\u1F22', '\u1F2A'),
+    ('\u1F23', '\u1F2B'),
+    ('\u1F24', '\u1F2C'),
+    ('\u1F25', '\u1F2D'),
+    ('\u1F26', '\u1F2E'),
+    ('\u1F27', '\u1F2F'),
+    ('\u1F28', '\u1F20'),
+    ('\u1F29', '\u1F21'),
+    ('\u1F2A', '\u1F22'),
+    ('\u1F2B', '\u1F23'),
+    ('\u1F2C', '\u1F24'),
+    ('\u1F2D', '\u1F25'),
+    ('\u1F2E', '\u1F26'),
+    ('\u1F2F', '\u1F27'),
+    ('\u1F30', '\u1F38'),
+    ('\u1F31', '\u1F39'),
+    ('\u1F32', '\u1F3A'),
+    ('\u1F33', '\u1F3B'),
+    ('\u1F34', '\u1F3C'),
+    ('\u1F35', '\u1F3D'),
+    ('\u1F36', '\u1F3E'),
+    ('\u1F37', '\u1F3F'),
+    ('\u1F38', '\u1F30'),
+    ('\u1F39', '\u1F31'),
+    ('\u1F3A', '\u1F32'),
+    ('\u1F3B', '\u1F33'),
+    ('\u1F3C', '\u1F34'),
+    ('\u1F3D', '\u1F35'),
+    ('\u1F3E', '\u1F36'),
+    ('\u1F3F', '\u1F37'),
+    ('\u1F40', '\u1F48'),
+    ('\u1F41', '\u1F49'),
+    ('\u1F42', '\u1F4A'),
+    ('\u1F43', '\u1F4B'),
+    ('\u1F44', '\u1F4C'),
+    ('\u1F45', '\u1F4D'),
+    ('\u1F48', '\u1F40'),
+    ('\u1F49', '\u1F41'),
+    ('\u1F4A', '\u1F42'),
+    ('\u1F4B', '\u1F43'),
+    ('\u1F4C', '\u1F44'),
+    ('\u1F4D', '\u1F45'),
+    ('\u1F51', '\u1F59'),
+    ('\u1F53', '\u1F5B'),
+    ('\u1F55', '\u1F5D'),
+    ('\u1F57', '\u1F5F'),
+    ('\u1F59', '\u1F51'),
+    ('\u1F5B', '\u1F53'),
+    ('\u1F5D', '\u1F55'),
+    ('\u1F5F', '\u1F57'),
+    ('\u1F60', '\u1F68'),
+    ('\u1F61', '\u1F69'),
+    ('\u1F62', '\u1F6A'),
+    ('\u1F63', '\u1F6B'),
+    ('\u1F64', '\u1F6C'),
+    ('\u1F65', '\u1F6D'),
+    ('\u1F66', '\u1F6E'),
+    ('\u1F67', '\u1F6F'),
+    ('\u1F68', '\u1F60'),
+    ('\u1F69', '\u1F61'),
+    ('\u1F6A', '\u1F62'),
+    ('\u1F6B', '\u1F63'),
+    ('\u1F6C', '\u1F64'),
+    ('\u1F6D', '\u1F65'),
+    ('\u1F6E', '\u1F66'),
+    ('\u1F6F', '\u1F67'),
+    ('\u1F70', '\u1FBA'),
+    ('\u1F71', '\u1FBB'),
+    ('\u1F72', '\u1FC8'),
+    ('\u1F73', '\u1FC9'),
+    ('\u1F74', '\u1FCA'),
+    ('\u1F75', '\u1FCB'),
+    ('\u1F76', '\u1FDA'),
+    ('\u1F77', '\u1FDB'),
+    ('\u1F78', '\u1FF8'),
+    ('\u1F79', '\u1FF9'),
+    ('\u1F7A', '\u1FEA'),
+    ('\u1F7B', '\u1FEB'),
+    ('\u1F7C', '\u1FFA'),
+    ('\u1F7D', '\u1FFB'),
+    ('\u1F80', '\u1F88'),
+    ('\u1F81', '\u1F89'),
+    ('\u1F82', '\u1F8A'),
+    ('\u1F83', '\u1F8B'),
+    ('\u1F84', '\u1F8C'),
+    ('\u1F85', '\u1F8D'),
+    ('\u1F86', '\u1F8E'),
+    ('\u1F87', '\u1F8F'),
+    ('\u1F88', '\u1F80'),
+    ('\u1F89', '\u1F81'),
+    ('\u1F8A', '\u1F82'),
+    ('\u1F8B', '\u1F83'),
+    ('\u1F8C', '\u1F84'),
+    ('\u1F8D', '\u1F85'),
+    ('\u1F8E', '\u1F86'),
+    ('\u1F8F', '\u1F87'),
+    ('\u1F90', '\u1F98'),
+    ('\u1F91', '\u1F99'),
+    ('\u1F92', '\u1F9A'),
+    ('\u1F93', '\u1F9B'),
+    ('\u1F94', '\u1F9C'),
+    ('\u1F95', '\u1F9D'),
+    ('\u1F96', '\u1F9E'),
+    ('\u1F97', '\u1F9F'),
+    ('\u1F98', '\u1F90'),
+    ('\u1F99', '\u1F91'),
+    ('\u1F9A', '\u1F92'),
+    ('\u1F9B', '\u1F93'),
+    ('\u1F9C', '\u1F94'),
+    ('\u1F9D', '\u1F95'),
+    ('\u1F9E', '\u1F96'),
+    ('\u1F9F', '\u1F97'),
+    ('\u1FA0', '\u1FA8'),
+    ('\u1FA1', '\u1FA9'),
+    ('\u1FA2', '\u1FAA'),
+    ('\u1FA3', '\u1FAB'),
+    ('\u1FA4', '\u1FAC'),
+    ('\u1FA5', '\u1FAD'),
+    ('\u1FA6', '\u1FAE'),
+    ('\u1FA7', '\u1FAF'),
+    ('\u1FA8', '\u1FA0'),
+    ('\u1FA9', '\u1FA1'),
+    ('\u1FAA', '\u1FA2'),
+    ('\u1FAB', '\u1FA3'),
+    ('\u1FAC', '\u1FA4'),
+    ('\u1FAD', '\u1FA5'),
+    ('\u1FAE', '\u1FA6'),
+    ('\u1FAF', '\u1FA7'),
+    ('\u1FB0', '\u1FB8'),
+    ('\u1FB1', '\u1FB9'),
+    ('\u1FB3', '\u1FBC'),
+    ('\u1FB8', '\u1FB0'),
+    ('\u1FB9', '\u1FB1'),
+    ('\u1FBA', '\u1F70'),
+    ('\u1FBB', '\u1F71'),
+    ('\u1FBC', '\u1FB3'),
+    ('\u1FBE', '\u0345'),
+    ('\u1FBE', '\u0399'),
+    ('\u1FBE', '\u03B9'),
+    ('\u1FC3', '\u1FCC'),
+    ('\u1FC8', '\u1F72'),
+    ('\u1FC9', '\u1F73'),
+    ('\u1FCA', '\u1F74'),
+    ('\u1FCB', '\u1F75'),
+    ('\u1FCC', '\u1FC3'),
+    ('\u1FD0', '\u1FD8'),
+    ('\u1FD1', '\u1FD9'),
+    ('\u1FD8', '\u1FD0'),
+    ('\u1FD9', '\u1FD1'),
+    ('\u1FDA', '\u1F76'),
+    ('\u1FDB', '\u1F77'),
+    ('\u1FE0', '\u1FE8'),
+    ('\u1FE1', '\u1FE9'),
+    ('\u1FE5', '\u1FEC'),
+    ('\u1FE8', '\u1FE0'),
+    ('\u1FE9', '\u1FE1'),
+    ('\u1FEA', '\u1F7A'),
+    ('\u1FEB', '\u1F7B'),
+    ('\u1FEC', '\u1FE5'),
+    ('\u1FF3', '\u1FFC'),
+    ('\u1FF8', '\u1F78'),
+    ('\u1FF9', '\u1F79'),
+    ('\u1FFA', '\u1F7C'),
+    ('\u1FFB', '\u1F7D'),
+    ('\u1FFC', '\u1FF3'),
+    ('\u2126', '\u03A9'),
+    ('\u2126', '\u03C9'),
+    ('\u212A', '\x4b'),
+    ('\u212A', '\x6b'),
+    ('\u212B', '\u00C5'),
+    ('\u212B', '\u00E5'),
+    ('\u2132', '\u214E'),
+    ('\u214E', '\u2132'),
+    ('\u2160', '\u2170'),
+    ('\u2161', '\u2171'),
+    ('\u2162', '\u2172'),
+    ('\u2163', '\u2173'),
+    ('\u2164', '\u2174'),
+    ('\u2165', '\u2175'),
+    ('\u2166', '\u2176'),
+    ('\u2167', '\u2177'),
+    ('\u2168', '\u2178'),
+    ('\u2169', '\u2179'),
+    ('\u216A', '\u217A'),
+    ('\u216B', '\u217B'),
+    ('\u216C', '\u217C'),
+    ('\u216D', '\u217D'),
+    ('\u216E', '\u217E'),
+    ('\u216F', '\u217F'),
+    ('\u2170', '\u2160'),
+    ('\u2171', '\u2161'),
+    ('\u2172', '\u2162'),
+    ('\u2173', '\u2163'),
+    ('\u2174', '\u2164'),
+    ('\u2175', '\u2165'),
+    ('\u2176', '\u2166'),
+    ('\u2177', '\u2167'),
+    ('\u2178', '\u2168'),
+    ('\u2179', '\u2169'),
+    ('\u217A', '\u216A'),
+    ('\u217B', '\u216B'),
+    ('\u217C', '\u216C'),
+    ('\u217D', '\u216D'),
+    ('\u217E', '\u216E'),
+    ('\u217F', '\u216F'),
+    ('\u2183', '\u2184'),
+    ('\u2184', '\u2183'),
+    ('\u24B6', '\u24D0'),
+    ('\u24B7', '\u24D1'),
+    ('\u24B8', '\u24D2'),
+    ('\u24B9', '\u24D3'),
+    ('\u24BA', '\u24D4'),
+    ('\u24BB', '\u24D5'),
+    ('\u24BC', '\u24D6'),
+    ('\u24BD', '\u24D7'),
+    ('\u24BE', '\u24D8'),
+    ('\u24BF', '\u24D9'),
+    ('\u24C0', '\u24DA'),
+    ('\u24C1', '\u24DB'),
+    ('\u24C2', '\u24DC'),
+    ('\u24C3', '\u24DD'),
+    ('\u24C4', '\u24DE'),
+    ('\u24C5', '\u24DF'),
+    ('\u24C6', '\u24E0'),
+    ('\u24C7', '\u24E1'),
+    ('\u24C8', '\u24E2'),
+    ('\u24C9', '\u24E3'),
+    ('\u24CA', '\u24E4'),
+    ('\u24CB', '\u24E5'),
+    ('\u24CC', '\u24E6'),
+    ('\u24CD', '\u24E7'),
+    ('\u24CE', '\u24E8'),
+    ('\u24CF', '\u24E9'),
+    ('\u24D0', '\u24B6'),
+    ('\u24D1', '\u24B7'),
+    ('\u24D2', '\u24B8'),
+    ('\u24D3', '\u24B9'),
+    ('\u24D4', '\u24BA'),
+    ('\u24D5', '\u24BB'),
+    ('\u24D6', '\u24BC'),
+    ('\u24D7', '\u24BD'),
+    ('\u24D8', '\u24BE'),
+    ('\u24D9', '\u24BF'),
+    ('\u24DA', '\u24C0'),
+    ('\u24DB', '\u24C1'),
+    ('\u24DC', '\u24C2'),
+    ('\u24DD', '\u24C3'),
+    ('\u24DE', '\u24C4'),
+    ('\u24DF', '\u24C5'),
+    ('\u24E0', '\u24C6'),
+    ('\u24E1', '\u24C7'),
+    ('\u24E2', '\u24C8'),
+    ('\u24E3', '\u24C9'),
+    ('\u24E4', '\u24CA'),
+    ('\u24E5', '\u24CB'),
+    ('\u24E6', '\u24CC'),
+    ('\u24E7', '\u24CD'),
+    ('\u24E8', '\u24CE'),
+    ('\u24E9', '\u24CF'),
+    ('\u2C00', '\u2C30'),
+    ('\u2C01', '\u2C31'),
+    ('\u2C02', '\u2C32'),
+    ('\u2C03', '\u2C33'),
+    ('\u2C04', '\u2C34'),
+    ('\u2C05', '\u2C35'),
+    ('\u2C06', '\u2C36'),
+    ('\u2C07', '\u2C37'),
+    ('\u2C08', '\u2C38'),
+    ('\u2C09', '\u2C39'),
+    ('\u2C0A', '\u2C3A'),
+    ('\u2C0B', '\u2C3B'),
+    ('\u2C0C', '\u2C3C'),
+    ('\u2C0D', '\u2C3D'),
+    ('\u2C0E', '\u2C3E'),
+    ('\u2C0F', '\u2C3F'),
+    ('\u2C10', '\u2C40'),
+    ('\u2C11', '\u2C41'),
+    ('\u2C12', '\u2C42'),
+    ('\u2C13', '\u2C43'),
+    ('\u2C14', '\u2C44'),
+    ('\u2C15', '\u2C45'),
+    ('\u2C16', '\u2C46'),
+    ('\u2C17', '\u2C47'),
+    ('\u2C18', '\u2C48'),
+    ('\u2C19', '\u2C49'),
+    ('\u2C1A', '\u2C4A'),
+    ('\u2C1B', '\u2C4B'),
+    ('\u2C1C', '\u2C4C'),
+    ('\u2C1D', '\u2C4D'),
+    ('\u2C1E', '\u2C4E'),
+    ('\u2C1F', '\u2C4F'),
+    ('\u2C20', '\u2C50'),
+    ('\u2C21', '\u2C51'),
+    ('\u2C22', '\u2C52'),
+    ('\u2C23', '\u2C53'),
+    ('\u2C24', '\u2C54'),
+    ('\u2C25', '\u2C55'),
+    ('\u2C26', '\u2C56'),
+    ('\u2C27', '\u2C57'),
+    ('\u2C28', '\u2C58'),
+    ('\u2C29', '\u2C59'),
+    ('\u2C2A', '\u2C5A'),
+    ('\u2C2B', '\u2C5B'),
+    ('\u2C2C', '\u2C5C'),
+    ('\u2C2D', '\u2C5D'),
+    ('\u2C2E', '\u2C5E'),
+    ('\u2C2F', '\u2C5F'),
+    ('\u2C30', '\u2C00'),
+    ('\u2C31', '\u2C01'),
+    ('\u2C32', '\u2C02'),
+    ('\u2C33', '\u2C03'),
+    ('\u2C34', '\u2C04'),
+    ('\u2C35', '\u2C05'),
+    ('\u2C36', '\u2C06'),
+    ('\u2C37', '\u2C07'),
+    ('\u2C38', '\u2C08'),
+    ('\u2C39', '\u2C09'),
+    ('\u2C3A', '\u2C0A'),
+    ('\u2C3B', '\u2C0B'),
+    ('\u2C3C', '\u2C0C'),
+    ('\u2C3D', '\u2C0D'),
+    ('\u2C3E', '\u2C0E'),
+    ('\u2C3F', '\u2C0F'),
+    ('\u2C40', '\u2C10'),
+    ('\u2C41', '\u2C11'),
+    ('\u2C42', '\u2C12'),
+    ('\u2C43', '\u2C13'),
+    ('\u2C44', '\u2C14'),
+    ('\u2C45', '\u2C15'),
+    ('\u2C46', '\u2C16'),
+    ('\u2C47', '\u2C17'),
+    ('\u2C48', '\u2C18'),
+    ('\u2C49', '\u2C19'),
+    ('\u2C4A', '\u2C1A'),
+    ('\u2C4B', '\u2C1B'),
+    ('\u2C4C', '\u2C1C'),
+    ('\u2C4D', '\u2C1D'),
+    ('\u2C4E', '\u2C1E'),
+    ('\u2C4F', '\u2C1F'),
+    ('\u2C50', '\u2C20'),
+    ('\u2C51', '\u2C21'),
+    ('\u2C52', '\u2C22'),
+    ('\u2C53', '\u2C23'),
+    ('\u2C54', '\u2C24'),
+    ('\u2C55', '\u2C25'),
+    ('\u2C56', '\u2C26'),
+    ('\u2C57', '\u2C27'),
+    ('\u2C58', '\u2C28'),
+    ('\u2C59', '\u2C29'),
+    ('\u2C5A', '\u2C2A'),
+    ('\u2C5B', '\u2C2B'),
+    ('\u2C5C', '\u2C2C'),
+    ('\u2C5D', '\u2C2D'),
+    ('\u2C5E', '\u2C2E'),
+    ('\u2C5F', '\u2C2F'),
+    ('\u2C60', '\u2C61'),
+    ('\u2C61', '\u2C60'),
+    ('\u2C62', '\u026B'),
+    ('\u2C63', '\u1D7D'),
+    ('\u2C64', '\u027D'),
+    ('\u2C65', '\u023A'),
+    ('\u2C66', '\u023E'),
+    ('\u2C67', '\u2C68'),
+    ('\u2C68', '\u2C67'),
+    ('\u2C69', '\u2C6A'),
+    ('\u2C6A', '\u2C69'),
+    ('\u2C6B', '\u2C6C'),
+    ('\u2C6C', '\u2C6B'),
+    ('\u2C6D', '\u0251'),
+    ('\u2C6E', '\u0271'),
+    ('\u2C6F', '\u0250'),
+    ('\u2C70', '\u0252'),
+    ('\u2C72', '\u2C73'),
+    ('\u2C73', '\u2C72'),
+    ('\u2C75', '\u2C76'),
+    ('\u2C76', '\u2C75'),
+    ('\u2C7E', '\u023F'),
+    ('\u2C7F', '\u0240'),
+    ('\u2C80', '\u2C81'),
+    ('\u2C81', '\u2C80'),
+    ('\u2C82', '\u2C83'),
+    ('\u2C83', '\u2C82'),
+    ('\u2C84', '\u2C85'),
+    ('\u2C85', '\u2C84'),
+    ('\u2C86', '\u2C87'),
+    ('\u2C87', '\u2C86'),
+    ('\u2C88', '\u2C89'),
+    ('\u2C89', '\u2C88'),
+    ('\u2C8A', '\u2C8B'),
+    ('\u2C8B', '\u2C8A'),
+    ('\u2C8C', '\u2C8D'),
+    ('\u2C8D', '\u2C8C'),
+    ('\u2C8E', '\u2C8F'),
+    ('\u2C8F', '\u2C8E'),
+    ('\u2C90', '\u2C91'),
+    ('\u2C91', '\u2C90'),
+    ('\u2C92', '\u2C93'),
+    ('\u2C93', '\u2C92'),
+    ('\u2C94', '\u2C95'),
+    ('\u2C95', '\u2C94'),
+    ('\u2C96', '\u2C97'),
+    ('\u2C97', '\u2C96'),
+    ('\u2C98', '\u2C99'),
+    ('\u2C99', '\u2C98'),
+    ('\u2C9A', '\u2C9B'),
+    ('\u2C9B', '\u2C9A'),
+    ('\u2C9C', '\u2C9D'),
+    ('\u2C9D', '\u2C9C'),
+    ('\u2C9E', '\u2C9F'),
+    ('\u2C9F', '\u2C9E'),
+    ('\u2CA0', '\u2CA1'),
+    ('\u2CA1', '\u2CA0'),
+    ('\u2CA2', '\u2CA3'),
+    ('\u2CA3', '\u2CA2'),
+    ('\u2CA4', '\u2CA5'),
+    ('\u2CA5', '\u2CA4'),
+    ('\u2CA6', '\u2CA7'),
+    ('\u2CA7', '\u2CA6'),
+    ('\u2CA8', '\u2CA9'),
+    ('\u2CA9', '\u2CA8'),
+    ('\u2CAA', '\u2CAB'),
+    ('\u2CAB', '\u2CAA'),
+    ('\u2CAC', '\u2CAD'),
+    ('\u2CAD', '\u2CAC'),
+    ('\u2CAE', '\u2CAF'),
+    ('\u2CAF', '\u2CAE'),
+    ('\u2CB0', '\u2CB1'),
+    ('\u2CB1', '\u2CB0'),
+    ('\u2CB2', '\u2CB3'),
+    ('\u2CB3', '\u2CB2'),
+    ('\u2CB4', '\u2CB5'),
+    ('\u2CB5', '\u2CB4'),
+    ('\u2CB6', '\u2CB7'),
+    ('\u2CB7', '\u2CB6'),
+    ('\u2CB8', '\u2CB9'),
+    ('\u2CB9', '\u2CB8'),
+    ('\u2CBA', '\u2CBB'),
+    ('\u2CBB', '\u2CBA'),
+    ('\u2CBC', '\u2CBD'),
+    ('\u2CBD', '\u2CBC'),
+    ('\u2CBE', '\u2CBF'),
+    ('\u2CBF', '\u2CBE'),
+    ('\u2CC0', '\u2CC1'),
+    ('\u2CC1', '\u2CC0'),
+    ('\u2CC2', '\u2CC3'),
+    ('\u2CC3', '\u2CC2'),
+    ('\u2CC4', '\u2CC5'),
+    ('\u2CC5', '\u2CC4'),
+    ('\u2CC6', '\u2CC7'),
+    ('\u2CC7', '\u2CC6'),
+    ('\u2CC8', '\u2CC9'),
+    ('\u2CC9', '\u2CC8'),
+    ('\u2CCA', '\u2CCB'),
+    ('\u2CCB', '\u2CCA'),
+    ('\u2CCC', '\u2CCD'),
+    ('\u2CCD', '\u2CCC'),
+    ('\u2CCE', '\u2CCF'),
+    ('\u2CCF', '\u2CCE'),
+    ('\u2CD0', '\u2CD1'),
+    ('\u2CD1', '\u2CD0'),
+    ('\u2CD2', '\u2CD3'),
+    ('\u2CD3', '\u2CD2'),
+    ('\u2CD4', '\u2CD5'),
+    ('\u2CD5', '\u2CD4'),
+    ('\u2CD6', '\u2CD7'),
+    ('\u2CD7', '\u2CD6'),
+    ('\u2CD8', '\u2CD9'),
+    ('\u2CD9', '\u2CD8'),
+    ('\u2CDA', '\u2CDB'),
+    ('\u2CDB', '\u2CDA'),
+    ('\u2CDC', '\u2CDD'),
+    ('\u2CDD', '\u2CDC'),
+    ('\u2CDE', '\u2CDF'),
+    ('\u2CDF', '\u2CDE'),
+    ('\u2CE0', '\u2CE1'),
+    ('\u2CE1', '\u2CE0'),
+    ('\u2CE2', '\u2CE3'),
+    ('\u2CE3', '\u2CE2'),
+    ('\u2CEB', '\u2CEC'),
+    ('\u2CEC', '\u2CEB'),
+    ('\u2CED', '\u2CEE'),
+    ('\u2CEE', '\u2CED'),
+    ('\u2CF2', '\u2CF3'),
+    ('\u2CF3', '\u2CF2'),
+    ('\u2D00', '\u10A0'),
+    ('\u2D01', '\u10A1'),
+    ('\u2D02', '\u10A2'),
+    ('\u2D03', '\u10A3'),
+    ('\u2D04', '\u10A4'),
+    ('\u2D05', '\u10A5'),
+    ('\u2D06', '\u10A6'),
+    ('\u2D07', '\u10A7'),
+    ('\u2D08', '\u10A8'),
+    ('\u2D09', '\u10A9'),
+    ('\u2D0A', '\u10AA'),
+    ('\u2D0B', '\u10AB'),
+    ('\u2D0C', '\u10AC'),
+    ('\u2D0D', '\u10AD'),
+    ('\u2D0E', '\u10AE'),
+    ('\u2D0F', '\u10AF'),
+    ('\u2D10', '\u10B0'),
+    ('\u2D11', '\u10B1'),
+    ('\u2D12', '\u10B2'),
+    ('\u2D13', '\u10B3'),
+    ('\u2D14', '\u10B4'),
+    ('\u2D15', '\u10B5'),
+    ('\u2D16', '\u10B6'),
+    ('\u2D17', '\u10B7'),
+    ('\u2D18', '\u10B8'),
+    ('\u2D19', '\u10B9'),
+    ('\u2D1A', '\u10BA'),
+    ('\u2D1B', '\u10BB'),
+    ('\u2D1C', '\u10BC'),
+    ('\u2D1D', '\u10BD'),
+    ('\u2D1E', '\u10BE'),
+    ('\u2D1F', '\u10BF'),
+    ('\u2D20', '\u10C0'),
+    ('\u2D21', '\u10C1'),
+    ('\u2D22', '\u10C2'),
+    ('\u2D23', '\u10C3'),
+    ('\u2D24', '\u10C4'),
+    ('\u2D25', '\u10C5'),
+    ('\u2D27', '\u10C7'),
+    ('\u2D2D', '\u10CD'),
+    ('\uA640', '\uA641'),
+    ('\uA641', '\uA640'),
+    ('\uA642', '\uA643'),
+    ('\uA643', '\uA642'),
+    ('\uA644', '\uA645'),
+    ('\uA645', '\uA644'),
+    ('\uA646', '\uA647'),
+    ('\uA647', '\uA646'),
+    ('\uA648', '\uA649'),
+    ('\uA649', '\uA648'),
+    ('\uA64A', '\u1C88'),
+    ('\uA64A', '\uA64B'),
+    ('\uA64B', '\u1C88'),
+    ('\uA64B', '\uA64A'),
+    ('\uA64C', '\uA64D'),
+    ('\uA64D', '\uA64C'),
+    ('\uA64E', '\uA64F'),
+    ('\uA64F', '\uA64E'),
+    ('\uA650', '\uA651'),
+    ('\uA651', '\uA650'),
+    ('\uA652', '\uA653'),
+    ('\uA653', '\uA652'),
+    ('\uA654', '\uA655'),
+    ('\uA655', '\uA654'),
+    ('\uA656', '\uA657'),
+    ('\uA657', '\uA656'),
+    ('\uA658', '\uA659'),
+    ('\uA659', '\uA658'),
+    ('\uA65A', '\uA65B'),
+    ('\uA65B', '\uA65A'),
+    ('\uA65C', '\uA65D'),
+    ('\uA65D', '\uA65C'),
+    ('\uA65E', '\uA65F'),
+    ('\uA65F', '\uA65E'),
+    ('\uA660', '\uA661'),
+    ('\uA661', '\uA660'),
+    ('\uA662', '\uA663'),
+    ('\uA663', '\uA662'),
+    ('\uA664', '\uA665'),
+    ('\uA665', '\uA664'),
+    ('\uA666', '\uA667'),
+    ('\uA667', '\uA666'),
+    ('\uA668', '\uA669'),
+    ('\uA669', '\uA668'),
+    ('\uA66A', '\uA66B'),
+    ('\uA66B', '\uA66A'),
+    ('\uA66C', '\uA66D'),
+    ('\uA66D', '\uA66C'),
+    ('\uA680', '\uA681'),
+    ('\uA681', '\uA680'),
+    ('\uA682', '\uA683'),
+    ('\uA683', '\uA682'),
+    ('\uA684', '\uA685'),
+    ('\uA685', '\uA684'),
+    ('\uA686', '\uA687'),
+    ('\uA687', '\uA686'),
+    ('\uA688', '\uA689'),
+    ('\uA689', '\uA688'),
+    ('\uA68A', '\uA68B'),
+    ('\uA68B', '\uA68A'),
+    ('\uA68C', '\uA68D'),
+    ('\uA68D', '\uA68C'),
+    ('\uA68E', '\uA68F'),
+    ('\uA68F', '\uA68E'),
+    ('\uA690', '\uA691'),
+    ('\uA691', '\uA690'),
+    ('\uA692', '\uA693'),
+    ('\uA693', '\uA692'),
+    ('\uA694', '\uA695'),
+    ('\uA695', '\uA694'),
+    ('\uA696', '\uA697'),
+    ('\uA697', '\uA696'),
+    ('\uA698', '\uA699'),
+    ('\uA699', '\uA698'),
+    ('\uA69A', '\uA69B'),
+    ('\uA69B', '\uA69A'),
+    ('\uA722', '\uA723'),
+    ('\uA723', '\uA722'),
+    ('\uA724', '\uA725'),
+    ('\uA725', '\uA724'),
+    ('\uA726', '\uA727'),
+    ('\uA727', '\uA726'),
+    ('\uA728', '\uA729'),
+    ('\uA729', '\uA728'),
+    ('\uA72A', '\uA72B'),
+    ('\uA72B', '\uA72A'),
+    ('\uA72C', '\uA72D'),
+    ('\uA72D', '\uA72C'),
+    ('\uA72E', '\uA72F'),
+    ('\uA72F', '\uA72E'),
+    ('\uA732', '\uA733'),
+    ('\uA733', '\uA732'),
+    ('\uA734', '\uA735'),
+    ('\uA735', '\uA734'),
+    ('\uA736', '\uA737'),
+    ('\uA737', '\uA736'),
+    ('\uA738', '\uA739'),
+    ('\uA739', '\uA738'),
+    ('\uA73A', '\uA73B'),
+    ('\uA73B', '\uA73A'),
+    ('\uA73C', '\uA73D'),
+    ('\uA73D', '\uA73C'),
+    ('\uA73E', '\uA73F'),
+    ('\uA73F', '\uA73E'),
+    ('\uA740', '\uA741'),
+    ('\uA741', '\uA740'),
+    ('\uA742', '\uA743'),
+    ('\uA743', '\uA742'),
+    ('\uA744', '\uA745'),
+    ('\uA745', '\uA744'),
+    ('\uA746', '\uA747'),
+    ('\uA747', '\uA746'),
+    ('\uA748', '\uA749'),
+    ('\uA749', '\uA748'),
+    ('\uA74A', '\uA74B'),
+    ('\uA74B', '\uA74A'),
+    ('\uA74C', '\uA74D'),
+    ('\uA74D', '\uA74C'),
+    ('\uA74E', '\uA74F'),
+    ('\uA74F', '\uA74E'),
+    ('\uA750', '\uA751'),
+    ('\uA751', '\uA750'),
+    ('\uA752', '\uA753'),
+    ('\uA753', '\uA752'),
+    ('\uA754', '\uA755'),
+    ('\uA755', '\uA754'),
+    ('\uA756', '\uA757'),
+    ('\uA757', '\uA756'),
+    ('\uA758', '\uA759'),
+    ('\uA759', '\uA758'),
+    ('\uA75A', '\uA75B'),
+    ('\uA75B', '\uA75A'),
+    ('\uA75C', '\uA75D'),
+    ('\uA75D', '\uA75C'),
+    ('\uA75E', '\uA75F'),
+    ('\uA75F', '\uA75E'),
+    ('\uA760', '\uA761'),
+    ('\uA761', '\uA760'),
+    ('\uA762', '\uA763'),
+    ('\uA763', '\uA762'),
+    ('\uA764', '\uA765'),
+    ('\uA765', '\uA764'),
+    ('\uA766', '\uA767'),
+    ('\uA767', '\uA766'),
+    ('\uA768', '\uA769'),
+    ('\uA769', '\uA768'),
+    ('\uA76A', '\uA76B'),
+    ('\uA76B', '\uA76A'),
+    ('\uA76C', '\uA76D'),
+    ('\uA76D', '\uA76C'),
+    ('\uA76E', '\uA76F'),
+    ('\uA76F', '\uA76E'),
+    ('\uA779', '\uA77A'),
+    ('\uA77A', '\uA779'),
+    ('\uA77B', '\uA77C'),
+    ('\uA77C', '\uA77B'),
+    ('\uA77D', '\u1D79'),
+    ('\uA77E', '\uA77F'),
+    ('\uA77F', '\uA77E'),
+    ('\uA780', '\uA781'),
+    ('\uA781', '\uA780'),
+    ('\uA782', '\uA783'),
+    ('\uA783', '\uA782'),
+    ('\uA784', '\uA785'),
+    ('\uA785', '\uA784'),
+    ('\uA786', '\uA787'),
+    ('\uA787', '\uA786'),
+    ('\uA78B', '\uA78C'),
+    ('\uA78C', '\uA78B'),
+    ('\uA78D', '\u0265'),
+    ('\uA790', '\uA791'),
+    ('\uA791', '\uA790'),
+    ('\uA792', '\uA793'),
+    ('\uA793', '\uA792'),
+    ('\uA794', '\uA7C4'),
+    ('\uA796', '\uA797'),
+    ('\uA797', '\uA796'),
+    ('\uA798', '\uA799'),
+    ('\uA799', '\uA798'),
+    ('\uA79A', '\uA79B'),
+    ('\uA79B', '\uA79A'),
+    ('\uA79C', '\uA79D'),
+    ('\uA79D', '\uA79C'),
+    ('\uA79E', '\uA79F'),
+    ('\uA79F', '\uA79E'),
+    ('\uA7A0', '\uA7A1'),
+    ('\uA7A1', '\uA7A0'),
+    ('\uA7A2', '\uA7A3'),
+    ('\uA7A3', '\uA7A2'),
+    ('\uA7A4', '\uA7A5'),
+    ('\uA7A5', '\uA7A4'),
+    ('\uA7A6', '\uA7A7'),
+    ('\uA7A7', '\uA7A6'),
+    ('\uA7A8', '\uA7A9'),
+    ('\uA7A9', '\uA7A8'),
+    ('\uA7AA', '\u0266'),
+    ('\uA7AB', '\u025C'),
+    ('\uA7AC', '\u0261'),
+    ('\uA7AD', '\u026C'),
+    ('\uA7AE', '\u026A'),
+    ('\uA7B0', '\u029E'),
+    ('\uA7B1', '\u0287'),
+    ('\uA7B2', '\u029D'),
+    ('\uA7B3', '\uAB53'),
+    ('\uA7B4', '\uA7B5'),
+    ('\uA7B5', '\uA7B4'),
+    ('\uA7B6', '\uA7B7'),
+    ('\uA7B7', '\uA7B6'),
+    ('\uA7B8', '\uA7B9'),
+    ('\uA7B9', '\uA7B8'),
+    ('\uA7BA', '\uA7BB'),
+    ('\uA7BB', '\uA7BA'),
+    ('\uA7BC', '\uA7BD'),
+    ('\uA7BD', '\uA7BC'),
+    ('\uA7BE', '\uA7BF'),
+    ('\uA7BF', '\uA7BE'),
+    ('\uA7C0', '\uA7C1'),
+    ('\uA7C1', '\uA7C0'),
+    ('\uA7C2', '\uA7C3'),
+    ('\uA7C3', '\uA7C2'),
+    ('\uA7C4', '\uA794'),
+    ('\uA7C5', '\u0282'),
+    ('\uA7C6', '\u1D8E'),
+    ('\uA7C7', '\uA7C8'),
+    ('\uA7C8', '\uA7C7'),
+    ('\uA7C9', '\uA7CA'),
+    ('\uA7CA', '\uA7C9'),
+    ('\uA7D0', '\uA7D1'),
+    ('\uA7D1', '\uA7D0'),
+    ('\uA7D6', '\uA7D7'),
+    ('\uA7D7', '\uA7D6'),
+    ('\uA7D8', '\uA7D9'),
+    ('\uA7D9', '\uA7D8'),
+    ('\uA7F5', '\uA7F6'),
+    ('\uA7F6', '\uA7F5'),
+    ('\uAB53', '\uA7B3'),
+    ('\uAB70', '\u13A0'),
+    ('\uAB71', '\u13A1'),
+    ('\uAB72', '\u13A2'),
+    ('\uAB73', '\u13A3'),
+    ('\uAB74', '\u13A4'),
+    ('\uAB75', '\u13A5'),
+    ('\uAB76', '\u13A6'),
+    ('\uAB77', '\u13A7'),
+    ('\uAB78', '\u13A8'),
+    ('\uAB79', '\u13A9'),
+    ('\uAB7A', '\u13AA'),
+    ('\uAB7B', '\u13AB'),
+    ('\uAB7C', '\u13AC'),
+    ('\uAB7D', '\u13AD'),
+    ('\uAB7E', '\u13AE'),
+    ('\uAB7F', '\u13AF'),
+    ('\uAB80', '\u13B0'),
+    ('\uAB81', '\u13B1'),
+    ('\uAB82', '\u13B2'),
+    ('\uAB83', '\u13B3'),
+    ('\uAB84', '\u13B4'),
+    ('\uAB85', '\u13B5'),
+    ('\uAB86', '\u13B6'),
+    ('\uAB87', '\u13B7'),
+    ('\uAB88', '\u13B8'),
+    ('\uAB89', '\u13B9'),
+    ('\uAB8A', '\u13BA'),
+    ('\uAB8B', '\u13BB'),
+    ('\uAB8C', '\u13BC'),
+    ('\uAB8D', '\u13BD'),
+    ('\uAB8E', '\u13BE'),
+    ('\uAB8F', '\u13BF'),
+    ('\uAB90', '\u13C0'),
+    ('\uAB91', '\u13C1'),
+    ('\uAB92', '\u13C2'),
+    ('\uAB93', '\u13C3'),
+    ('\uAB94', '\u13C4'),
+    ('\uAB95', '\u13C5'),
+    ('\uAB96', '\u13C6'),
+    ('\uAB97', '\u13C7'),
+    ('\uAB98', '\u13C8'),
+    ('\uAB99', '\u13C9'),
+    ('\uAB9A', '\u13CA'),
+    ('\uAB9B', '\u13CB'),
+    ('\uAB9C', '\u13CC'),
+    ('\uAB9D', '\u13CD'),
+    ('\uAB9E', '\u13CE'),
+    ('\uAB9F', '\u13CF'),
+    ('\uABA0', '\u13D0'),
+    ('\uABA1', '\u13D1'),
+    ('\uABA2', '\u13D2'),
+    ('\uABA3', '\u13D3'),
+    ('\uABA4', '\u13D4'),
+    ('\uABA5', '\u13D5'),
+    ('\uABA6', '\u13D6'),
+    ('\uABA7', '\u13D7'),
+    ('\uABA8', '\u13D8'),
+    ('\uABA9', '\u13D9'),
+    ('\uABAA', '\u13DA'),
+    ('\uABAB', '\u13DB'),
+    ('\uABAC', '\u13DC'),
+    ('\uABAD', '\u13DD'),
+    ('\uABAE', '\u13DE'),
+    ('\uABAF', '\u13DF'),
+    ('\uABB0', '\u13E0'),
+    ('\uABB1', '\u13E1'),
+    ('\uABB2', '\u13E2'),
+    ('\uABB3', '\u13E3'),
+    ('\uABB4', '\u13E4'),
+    ('\uABB5', '\u13E5'),
+    ('\uABB6', '\u13E6'),
+    ('\uABB7', '\u13E7'),
+    ('\uABB8', '\u13E8'),
+    ('\uABB9', '\u13E9'),
+    ('\uABBA', '\u13EA'),
+    ('\uABBB', '\u13EB'),
+    ('\uABBC', '\u13EC'),
+    ('\uABBD', '\u13ED'),
+    ('\uABBE', '\u13EE'),
+    ('\uABBF', '\u13EF'),
+    ('\uFF21', '\uFF41'),
+    ('\uFF22', '\uFF42'),
+    ('\uFF23', '\uFF43'),
+    ('\uFF24', '\uFF44'),
+    ('\uFF25', '\uFF45'),
+    ('\uFF26', '\uFF46'),
+    ('\uFF27', '\uFF47'),
+    ('\uFF28', '\uFF48'),
+    ('\uFF29', '\uFF49'),
+    ('\uFF2A', '\uFF4A'),
+    ('\uFF2B', '\uFF4B'),
+    ('\uFF2C', '\uFF4C'),
+    ('\uFF2D', '\uFF4D'),
+    ('\uFF2E', '\uFF4E'),
+    ('\uFF2F', '\uFF4F'),
+    ('\uFF30', '\uFF50'),
+    ('\uFF31', '\uFF51'),
+    ('\uFF32', '\uFF52'),
+    ('\uFF33', '\uFF53'),
+    ('\uFF34', '\uFF54'),
+    ('\uFF35', '\uFF55'),
+    ('\uFF36', '\uFF56'),
+    ('\uFF37', '\uFF57'),
+    ('\uFF38', '\uFF58'),
+    ('\uFF39', '\uFF59'),
+    ('\uFF3A', '\uFF5A'),
+    ('\uFF41', '\uFF21'),
+    ('\uFF42', '\uFF22'),
+    ('\uFF43', '\uFF23'),
+    ('\uFF44', '\uFF24'),
+    ('\uFF45', '\uFF25'),
+    ('\uFF46', '\uFF26'),
+    ('\uFF47', '\uFF27'),
+    ('\uFF48', '\uFF28'),
+    ('\uFF49', '\uFF29'),
+    ('\uFF4A', '\uFF2A'),
+    ('\uFF4B', '\uFF2B'),
+    ('\uFF4C', '\uFF2C'),
+    ('\uFF4D', '\uFF2D'),
+    ('\uFF4E', '\uFF2E'),
+    ('\uFF4F', '\uFF2F'),
+    ('\uFF50', '\uFF30'),
+    ('\uFF51', '\uFF31'),
+    ('\uFF52', '\uFF32'),
+    ('\uFF53', '\uFF33'),
+    ('\uFF54', '\uFF34'),
+    ('\uFF55', '\uFF35'),
+    ('\uFF56', '\uFF36'),
+    ('\uFF57', '\uFF37'),
+    ('\uFF58', '\uFF38'),
+    ('\uFF59', '\uFF39'),
+    ('\uFF5A', '\uFF3A'),
+    ('\U00010400', '\U00010428'),
+    ('\U00010401', '\U00010429'),
+    ('\U00010402', '\U0001042A'),
+    ('\U00010403', '\U0001042B'),
+    ('\U00010404', '\U0001042C'),
+    ('\U00010405', '\U0001042D'),
+    ('\U00010406', '\U0001042E'),
+    ('\U00010407', '\U0001042F'),
+    ('\U00010408', '\U00010430'),
+    ('\U00010409', '\U00010431'),
+    ('\U0001040A', '\U00010432'),
+    ('\U0001040B', '\U00010433'),
+    ('\U0001040C', '\U00010434'),
+    ('\U0001040D', '\U00010435'),
+    ('\U0001040E', '\U00010436'),
+    ('\U0001040F', '\U00010437'),
+    ('\U00010410', '\U00010438'),
+    ('\U00010411', '\U00010439'),
+    ('\U00010412', '\U0001043A'),
+    ('\U00010413', '\U0001043B'),
+    ('\U00010414', '\U0001043C'),
+    ('\U00010415', '\U0001043D'),
+    ('\U00010416', '\U0001043E'),
+    ('\U00010417', '\U0001043F'),
+    ('\U00010418', '\U00010440'),
+    ('\U00010419', '\U00010441'),
+    ('\U0001041A', '\U00010442'),
+    ('\U0001041B', '\U00010443'),
+    ('\U0001041C', '\U00010444'),
+    ('\U0001041D', '\U00010445'),
+    ('\U0001041E', '\U00010446'),
+    ('\U0001041F', '\U00010447'),
+    ('\U00010420', '\U00010448'),
+    ('\U00010421', '\U00010449'),
+    ('\U00010422', '\U0001044A'),
+    ('\U00010423', '\U0001044B'),
+    ('\U00010424', '\U0001044C'),
+    ('\U00010425', '\U0001044D'),
+    ('\U00010426', '\U0001044E'),
+    ('\U00010427', '\U0001044F'),
+    ('\U00010428', '\U00010400'),
+    ('\U00010429', '\U00010401'),
+    ('\U0001042A', '\U00010402'),
+    ('\U0001042B', '\U00010403'),
+    ('\U0001042C', '\U00010404'),
+    ('\U0001042D', '\U00010405'),
+    ('\U0001042E', '\U00010406'),
+    ('\U0001042F', '\U00010407'),
+    ('\U00010430', '\U00010408'),
+    ('\U00010431', '\U00010409'),
+    ('\U00010432', '\U0001040A'),
+    ('\U00010433', '\U0001040B'),
+    ('\U00010434', '\U0001040C'),
+    ('\U00010435', '\U0001040D'),
+    ('\U00010436', '\U0001040E'),
+    ('\U00010437', '\U0001040F'),
+    ('\U00010438', '\U00010410'),
+    ('\U00010439', '\U00010411'),
+    ('\U0001043A', '\U00010412'),
+    ('\U0001043B', '\U00010413'),
+    ('\U0001043C', '\U00010414'),
+    ('\U0001043D', '\U00010415'),
+    ('\U0001043E', '\U00010416'),
+    ('\U0001043F', '\U00010417'),
+    ('\U00010440', '\U00010418'),
+    ('\U00010441', '\U00010419'),
+    ('\U00010442', '\U0001041A'),
+    ('\U00010443', '\U0001041B'),
+    ('\U00010444', '\U0001041C'),
+    ('\U00010445', '\U0001041D'),
+    ('\U00010446', '\U0001041E'),
+    ('\U00010447', '\U0001041F'),
+    ('\U00010448', '\U00010420'),
+    ('\U00010449', '\U00010421'),
+    ('\U0001044A', '\U00010422'),
+    ('\U0001044B', '\U00010423'),
+    ('\U0001044C', '\U00010424'),
+    ('\U0001044D', '\U00010425'),
+    ('\U0001044E', '\U00010426'),
+    ('\U0001044F', '\U00010427'),
+    ('\U000104B0', '\U000104D8'),
+    ('\U000104B1', '\U000104D9'),
+    ('\U000104B2', '\U000104DA'),
+    ('\U000104B3', '\U000104DB'),
+    ('\U000104B4', '\U000104DC'),
+    ('\U000104B5', '\U000104DD'),
+    ('\U000104B6', '\U000104DE'),
+    ('\U000104B7', '\U000104DF'),
+    ('\U000104B8', '\U000104E0'),
+    ('\U000104B9', '\U000104E1'),
+    ('\U000104BA', '\U000104E2'),
+    ('\U000104BB', '\U000104E3'),
+    ('\U000104BC', '\U000104E4'),
+    ('\U000104BD', '\U000104E5'),
+    ('\U000104BE', '\U000104E6'),
+    ('\U000104BF', '\U000104E7'),
+    ('\U000104C0', '\U000104E8'),
+    ('\U000104C1', '\U000104E9'),
+    ('\U000104C2', '\U000104EA'),
+    ('\U000104C3', '\U000104EB'),
+    ('\U000104C4', '\U000104EC'),
+    ('\U000104C5', '\U000104ED'),
+    ('\U000104C6', '\U000104EE'),
+    ('\U000104C7', '\U000104EF'),
+    ('\U000104C8', '\U000104F0'),
+    ('\U000104C9', '\U000104F1'),
+    ('\U000104CA', '\U000104F2'),
+    ('\U000104CB', '\U000104F3'),
+    ('\U000104CC', '\U000104F4'),
+    ('\U000104CD', '\U000104F5'),
+    ('\U000104CE', '\U000104F6'),
+    ('\U000104CF', '\U000104F7'),
+    ('\U000104D0', '\U000104F8'),
+    ('\U000104D1', '\U000104F9'),
+    ('\U000104D2', '\U000104FA'),
+    ('\U000104D3', '\U000104FB'),
+    ('\U000104D8', '\U000104B0'),
+    ('\U000104D9', '\U000104B1'),
+    ('\U000104DA', '\U000104B2'),
+    ('\U000104DB', '\U000104B3'),
+    ('\U000104DC', '\U000104B4'),
+    ('\U000104DD', '\U000104B5'),
+    ('\U000104DE', '\U000104B6'),
+    ('\U000104DF', '\U000104B7'),
+    ('\U000104E0', '\U000104B8'),
+    ('\U000104E1', '\U000104B9'),
+    ('\U000104E2', '\U000104BA'),
+    ('\U000104E3', '\U000104BB'),
+    ('\U000104E4', '\U000104BC'),
+    ('\U000104E5', '\U000104BD'),
+    ('\U000104E6', '\U000104BE'),
+    ('\U000104E7', '\U000104BF'),
+    ('\U000104E8', '\U000104C0'),
+    ('\U000104E9', '\U000104C1'),
+    ('\U000104EA', '\U000104C2'),
+    ('\U000104EB', '\U000104C3'),
+    ('\U000104EC', '\U000104C4'),
+    ('\U000104ED', '\U000104C5'),
+    ('\U000104EE', '\U000104C6'),
+    ('\U000104EF', '\U000104C7'),
+    ('\U000104F0', '\U000104C8'),
+    ('\U000104F1', '\U000104C9'),
+    ('\U000104F2', '\U000104CA'),
+    ('\U000104F3', '\U000104CB'),
+    ('\U000104F4', '\U000104CC'),
+    ('\U000104F5', '\U000104CD'),
+    ('\U000104F6', '\U000104CE'),
+    ('\U000104F7', '\U000104CF'),
+    ('\U000104F8', '\U000104D0'),
+    ('\U000104F9', '\U000104D1'),
+    ('\U000104FA', '\U000104D2'),
+    ('\U000104FB', '\U000104D3'),
+    ('\U00010570', '\U00010597'),
+    ('\U00010571', '\U00010598'),
+    ('\U00010572', '\U00010599'),
+    ('\U00010573', '\U0001059A'),
+    ('\U00010574', '\U0001059B'),
+    ('\U00010575', '\U0001059C'),
+    ('\U00010576', '\U0001059D'),
+    ('\U00010577', '\U0001059E'),
+    ('\U00010578', '\U0001059F'),
+    ('\U00010579', '\U000105A0'),
+    ('\U0001057A', '\U000105A1'),
+    ('\U0001057C', '\U000105A3'),
+    ('\U0001057D', '\U000105A4'),
+    ('\U0001057E', '\U000105A5'),
+    ('\U0001057F', '\U000105A6'),
+    ('\U00010580', '\U000105A7'),
+    ('\U00010581', '\U000105A8'),
+    ('\U00010582', '\U000105A9'),
+    ('\U00010583', '\U000105AA'),
+    ('\U00010584', '\U000105AB'),
+    ('\U00010585', '\U000105AC'),
+    ('\U00010586', '\U000105AD'),
+    ('\U00010587', '\U000105AE'),
+    ('\U00010588', '\U000105AF'),
+    ('\U00010589', '\U000105B0'),
+    ('\U0001058A', '\U000105B1'),
+    ('\U0001058C', '\U000105B3'),
+    ('\U0001058D', '\U000105B4'),
+    ('\U0001058E', '\U000105B5'),
+    ('\U0001058F', '\U000105B6'),
+    ('\U00010590', '\U000105B7'),
+    ('\U00010591', '\U000105B8'),
+    ('\U00010592', '\U000105B9'),
+    ('\U00010594', '\U000105BB'),
+    ('\U00010595', '\U000105BC'),
+    ('\U00010597', '\U00010570'),
+    ('\U00010598', '\U00010571'),
+    ('\U00010599', '\U00010572'),
+    ('\U0001059A', '\U00010573'),
+    ('\U0001059B', '\U00010574'),
+    ('\U0001059C', '\U00010575'),
+    ('\U0001059D', '\U00010576'),
+    ('\U0001059E', '\U00010577'),
+    ('\U0001059F', '\U00010578'),
+    ('\U000105A0', '\U00010579'),
+    ('\U000105A1', '\U0001057A'),
+    ('\U000105A3', '\U0001057C'),
+    ('\U000105A4', '\U0001057D'),
+    ('\U000105A5', '\U0001057E'),
+    ('\U000105A6', '\U0001057F'),
+    ('\U000105A7', '\U00010580'),
+    ('\U000105A8', '\U00010581'),
+    ('\U000105A9', '\U00010582'),
+    ('\U000105AA', '\U00010583'),
+    ('\U000105AB', '\U00010584'),
+    ('\U000105AC', '\U00010585'),
+    ('\U000105AD', '\U00010586'),
+    ('\U000105AE', '\U00010587'),
+    ('\U000105AF', '\U00010588'),
+    ('\U000105B0', '\U00010589'),
+    ('\U000105B1', '\U0001058A'),
+    ('\U000105B3', '\U0001058C'),
+    ('\U000105B4', '\U0001058D'),
+    ('\U000105B5', '\U0001058E'),
+    ('\U000105B6', '\U0001058F'),
+    ('\U000105B7', '\U00010590'),
+    ('\U000105B8', '\U00010591'),
+    ('\U000105B9', '\U00010592'),
+    ('\U000105BB', '\U00010594'),
+    ('\U000105BC', '\U00010595'),
+    ('\U00010C80', '\U00010CC0'),
+    ('\U00010C81', '\U00010CC1'),
+    ('\U00010C82', '\U00010CC2'),
+    ('\U00010C83', '\U00010CC3'),
+    ('\U00010C84', '\U00010CC4'),
+    ('\U00010C85', '\U00010CC5'),
+    ('\U00010C86', '\U00010CC6'),
+    ('\U00010C87', '\U00010CC7'),
+    ('\U00010C88', '\U00010CC8'),
+    ('\U00010C89', '\U00010CC9'),
+    ('\U00010C8A', '\U00010CCA'),
+    ('\U00010C8B', '\U00010CCB'),
+    ('\U00010C8C', '\U00010CCC'),
+    ('\U00010C8D', '\U00010CCD'),
+    ('\U00010C8E', '\U00010CCE'),
+    ('\U00010C8F', '\U00010CCF'),
+    ('\U00010C90', '\U00010CD0'),
+    ('\U00010C91', '\U00010CD1'),
+    ('\U00010C92', '\U00010CD2'),
+    ('\U00010C93', '\U00010CD3'),
+    ('\U00010C94', '\U00010CD4'),
+    ('\U00010C95', '\U00010CD5'),
+    ('\U00010C96', '\U00010CD6'),
+    ('\U00010C97', '\U00010CD7'),
+    ('\U00010C98', '\U00010CD8'),
+    ('\U00010C99', '\U00010CD9'),
+    ('\U00010C9A', '\U00010CDA'),
+    ('\U00010C9B', '\U00010CDB'),
+    ('\U00010C9C', '\U00010CDC'),
+    ('\U00010C9D', '\U00010CDD'),
+    ('\U00010C9E', '\U00010CDE'),
+    ('\U00010C9F', '\U00010CDF'),
+    ('\U00010CA0', '\U00010CE0'),
+    ('\U00010CA1', '\U00010CE1'),
+    ('\U00010CA2', '\U00010CE2'),
+    ('\U00010CA3', '\U00010CE3'),
+    ('\U00010CA4', '\U00010CE4'),
+    ('\U00010CA5', '\U00010CE5'),
+    ('\U00010CA6', '\U00010CE6'),
+    ('\U00010CA7', '\U00010CE7'),
+    ('\U00010CA8', '\U00010CE8'),
+    ('\U00010CA9', '\U00010CE9'),
+    ('\U00010CAA', '\U00010CEA'),
+    ('\U00010CAB', '\U00010CEB'),
+    ('\U00010CAC', '\U00010CEC'),
+    ('\U00010CAD', '\U00010CED'),
+    ('\U00010CAE', '\U00010CEE'),
+    ('\U00010CAF', '\U00010CEF'),
+    ('\U00010CB0', '\U00010CF0'),
+    ('\U00010CB1', '\U00010CF1'),
+    ('\U00010CB2', '\U00010CF2'),
+    ('\U00010CC0', '\U00010C80'),
+    ('\U00010CC1', '\U00010C81'),
+    ('\U00010CC2', '\U00010C82'),
+    ('\U00010CC3', '\U00010C83'),
+    ('\U00010CC4', '\U00010C84'),
+    ('\U00010CC5', '\U00010C85'),
+    ('\U00010CC6', '\U00010C86'),
+    ('\U00010CC7', '\U00010C87'),
+    ('\U00010CC8', '\U00010C88'),
+    ('\U00010CC9', '\U00010C89'),
+    ('\U00010CCA', '\U00010C8A'),
+    ('\U00010CCB', '\U00010C8B'),
+    ('\U00010CCC', '\U00010C8C'),
+    ('\U00010CCD', '\U00010C8D'),
+    ('\U00010CCE', '\U00010C8E'),
+    ('\U00010CCF', '\U00010C8F'),
+    ('\U00010CD0', '\U00010C90'),
+    ('\U00010CD1', '\U00010C91'),
+    ('\U00010CD2', '\U00010C92'),
+    ('\U00010CD3', '\U00010C93'),
+    ('\U00010CD4', '\U00010C94'),
+    ('\U00010CD5', '\U00010C95'),
+    ('\U00010CD6', '\U00010C96'),
+    ('\U00010CD7', '\U00010C97'),
+    ('\U00010CD8', '\U00010C98'),
+    ('\U00010CD9', '\U00010C99'),
+    ('\U00010CDA', '\U00010C9A'),
+    ('\U00010CDB', '\U00010C9B'),
+    ('\U00010CDC', '\U00010C9C'),
+    ('\U00010CDD', '\U00010C9D'),
+    ('\U00010CDE', '\U00010C9E'),
+    ('\U00010CDF', '\U00010C9F'),
+    ('\U00010CE0', '\U00010CA0'),
+    ('\U00010CE1', '\U00010CA1'),
+    ('\U00010CE2', '\U00010CA2'),
+    ('\U00010CE3', '\U00010CA3'),
+    ('\U00010CE4', '\U00010CA4'),
+    ('\U00010CE5', '\U00010CA5'),
+    ('\U00010CE6', '\U00010CA6'),
+    ('\U00010CE7', '\U00010CA7'),
+    ('\U00010CE8', '\U00010CA8'),
+    ('\U00010CE9', '\U00010CA9'),
+    ('\U00010CEA', '\U00010CAA'),
+    ('\U00010CEB', '\U00010CAB'),
+    ('\U00010CEC', '\U00010CAC'),
+    ('\U00010CED', '\U00010CAD'),
+    ('\U00010CEE', '\U00010CAE'),
+    ('\U00010CEF', '\U00010CAF'),
+    ('\U00010CF0', '\U00010CB0'),
+    ('\U00010CF1', '\U00010CB1'),
+    ('\U00010CF2', '\U00010CB2'),
+    ('\U000118A0', '\U000118C0'),
+    ('\U000118A1', '\U000118C1'),
+    ('\U000118A2', '\U000118C2'),
+    ('\U000118A3', '\U000118C3'),
+    ('\U000118A4', '\U000118C4'),
+    ('\U000118A5', '\U000118C5'),
+    ('\U000118A6', '\U000118C6'),
+    ('\U000118A7', '\U000118C7'),
+    ('\U000118A8', '\U000118C8'),
+    ('\U000118A9', '\U000118C9'),
+    ('\U000118AA', '\U000118CA'),
+    ('\U000118AB', '\U000118CB'),
+    ('\U000118AC', '\U000118CC'),
+    ('\U000118AD', '\U000118CD'),
+    ('\U000118AE', '\U000118CE'),
+    ('\U000118AF', '\U000118CF'),
+    ('\U000118B0', '\U000118D0'),
+    ('\U000118B1', '\U000118D1'),
+    ('\U000118B2', '\U000118D2'),
+    ('\U000118B3', '\U000118D3'),
+    ('\U000118B4', '\U000118D4'),
+    ('\U000118B5', '\U000118D5'),
+    ('\U000118B6', '\U000118D6'),
+    ('\U000118B7', '\U000118D7'),
+    ('\U000118B8', '\U000118D8'),
+    ('\U000118B9', '\U000118D9'),
+    ('\U000118BA', '\U000118DA'),
+    ('\U000118BB', '\U000118DB'),
+    ('\U000118BC', '\U000118DC'),
+    ('\U000118BD', '\U000118DD'),
+    ('\U000118BE', '\U000118DE'),
+    ('\U000118BF', '\U000118DF'),
+    ('\U000118C0', '\U000118A0'),
+    ('\U000118C1', '\U000118A1'),
+    ('\U000118C2', '\U000118A2'),
+    ('\U000118C3', '\U000118A3'),
+    ('\U000118C4', '\U000118A4'),
+    ('\U000118C5', '\U000118A5'),
+    ('\U000118C6', '\U000118A6'),
+    ('\U000118C7', '\U000118A7'),
+    ('\U000118C8', '\U000118A8'),
+    ('\U000118C9', '\U000118A9'),
+    ('\U000118CA', '\U000118AA'),
+    ('\U000118CB', '\U000118AB'),
+    ('\U000118CC', '\U000118AC'),
+    ('\U000118CD', '\U000118AD'),
+    ('\U000118CE', '\U000118AE'),
+    ('\U000118CF', '\U000118AF'),
+    ('\U000118D0', '\U000118B0'),
+    ('\U000118D1', '\U000118B1'),
+    ('\U000118D2', '\U000118B2'),
+    ('\U000118D3', '\U000118B3'),
+    ('\U000118D4', '\U000118B4'),
+    ('\U000118D5', '\U000118B5'),
+    ('\U000118D6', '\U000118B6'),
+    ('\U000118D7', '\U000118B7'),
+    ('\U000118D8', '\U000118B8'),
+    ('\U000118D9', '\U000118B9'),
+    ('\U000118DA', '\U000118BA'),
+    ('\U000118DB', '\U000118BB'),
+    ('\U000118DC', '\U000118BC'),
+    ('\U000118DD', '\U000118BD'),
+    ('\U000118DE', '\U000118BE'),
+    ('\U000118DF', '\U000118BF'),
+    ('\U00016E40', '\U00016E60'),
+    ('\U00016E41', '\U00016E61'),
+    ('\U00016E42', '\U00016E62'),
+    ('\U00016E43', '\U00016E63'),
+    ('\U00016E44', '\U00016E64'),
+    ('\U00016E45', '\U00016E65'),
+    ('\U00016E46', '\U00016E66'),
+    ('\U00016E47', '\U00016E67'),
+    ('\U00016E48', '\U00016E68'),
+    ('\U00016E49', '\U00016E69'),
+    ('\U00016E4A', '\U00016E6A'),
+    ('\U00016E4B', '\U00016E6B'),
+    ('\U00016E4C', '\U00016E6C'),
+    ('\U00016E4D', '\U00016E6D'),
+    ('\U00016E4E', '\U00016E6E'),
+    ('\U00016E4F', '\U00016E6F'),
+    ('\U00016E50', '\U00016E70'),
+    ('\U00016E51', '\U00016E71'),
+    ('\U00016E52', '\U00016E72'),
+    ('\U00016E53', '\U00016E73'),
+    ('\U00016E54', '\U00016E74'),
+    ('\U00016E55', '\U00016E75'),
+    ('\U00016E56', '\U00016E76'),
+    ('\U00016E57', '\U00016E77'),
+    ('\U00016E58', '\U00016E78'),
+    ('\U00016E59', '\U00016E79'),
+    ('\U00016E5A', '\U00016E7A'),
+    ('\U00016E5B', '\U00016E7B'),
+    ('\U00016E5C', '\U00016E7C'),
+    ('\U00016E5D', '\U00016E7D'),
+    ('\U00016E5E', '\U00016E7E'),
+    ('\U00016E5F', '\U00016E7F'),
+    ('\U00016E60', '\U00016E40'),
+    ('\U00016E61', '\U00016E41'),
+    ('\U00016E62', '\U00016E42'),
+    ('\U00016E63', '\U00016E43'),
+    ('\U00016E64', '\U00016E44'),
+    ('\U00016E65', '\U00016E45'),
+    ('\U00016E66', '\U00016E46'),
+    ('\U00016E67', '\U00016E47'),
+    ('\U00016E68', '\U00016E48'),
+    ('\U00016E69', '\U00016E49'),
+    ('\U00016E6A', '\U00016E4A'),
+    ('\U00016E6B', '\U00016E4B'),
+    ('\U00016E6C', '\U00016E4C'),
+    ('\U00016E6D', '\U00016E4D'),
+    ('\U00016E6E', '\U00016E4E'),
+    ('\U00016E6F', '\U00016E4F'),
+    ('\U00016E70', '\U00016E50'),
+    ('\U00016E71', '\U00016E51'),
+    ('\U00016E72', '\U00016E52'),
+    ('\U00016E73', '\U00016E53'),
+    ('\U00016E74', '\U00016E54'),
+    ('\U00016E75', '\U00016E55'),
+    ('\U00016E76', '\U00016E56'),
+    ('\U00016E77', '\U00016E57'),
+    ('\U00016E78', '\U00016E58'),
+    ('\U00016E79', '\U00016E59'),
+    ('\U00016E7A', '\U00016E5A'),
+    ('\U00016E7B', '\U00016E5B'),
+    ('\U00016E7C', '\U00016E5C'),
+    ('\U00016E7D', '\U00016E5D'),
+    ('\U00016E7E', '\U00016E5E'),
+    ('\U00016E7F', '\U00016E5F'),
+    ('\U0001E900', '\U0001E922'),
+    ('\U0001E901', '\U0001E923'),
+    ('\U0001E902', '\U0001E924'),
+    ('\U0001E903', '\U0001E925'),
+    ('\U0001E904', '\U0001E926'),
+    ('\U0001E905', '\U0001E927'),
+    ('\U0001E906', '\U0001E928'),
+    ('\U0001E907', '\U0001E929'),
+    ('\U0001E908', '\U0001E92A'),
+    ('\U0001E909', '\U0001E92B'),
+    ('\U0001E90A', '\U0001E92C'),
+    ('\U0001E90B', '\U0001E92D'),
+    ('\U0001E90C', '\U0001E92E'),
+    ('\U0001E90D', '\U0001E92F'),
+    ('\U0001E90E', '\U0001E930'),
+    ('\U0001E90F', '\U0001E931'),
+    ('\U0001E910', '\U0001E932'),
+    ('\U0001E911', '\U0001E933'),
+    ('\U0001E912', '\U0001E934'),
+    ('\U0001E913', '\U0001E935'),
+    ('\U0001E914', '\U0001E936'),
+    ('\U0001E915', '\U0001E937'),
+    ('\U0001E916', '\U0001E938'),
+    ('\U0001E917', '\U0001E939'),
+    ('\U0001E918', '\U0001E93A'),
+    ('\U0001E919', '\U0001E93B'),
+    ('\U0001E91A', '\U0001E93C'),
+    ('\U0001E91B', '\U0001E93D'),
+    ('\U0001E91C', '\U0001E93E'),
+    ('\U0001E91D', '\U0001E93F'),
+    ('\U0001E91E', '\U0001E940'),
+    ('\U0001E91F', '\U0001E941'),
+    ('\U0001E920', '\U0001E942'),
+    ('\U0001E921', '\U0001E943'),
+    ('\U0001E922', '\U0001E900'),
+    ('\U0001E923', '\U0001E901'),
+    ('\U0001E924', '\U0001E902'),
+    ('\U0001E925', '\U0001E903'),
+    ('\U0001E926', '\U0001E904'),
+    ('\U0001E927', '\U0001E905'),
+    ('\U0001E928', '\U0001E906'),
+    ('\U0001E929', '\U0001E907'),
+    ('\U0001E92A', '\U0001E908'),
+    ('\U0001E92B', '\U0001E909'),
+    ('\U0001E92C', '\U0001E90A'),
+    ('\U0001E92D', '\U0001E90B'),
+    ('\U0001E92E', '\U0001E90C'),
+    ('\U0001E92F', '\U0001E90D'),
+    ('\U0001E930', '\U0001E90E'),
+    ('\U0001E931', '\U0001E90F'),
+    ('\U0001E932', '\U0001E910'),
+    ('\U0001E933', '\U0001E911'),
+    ('\U0001E934', '\U0001E912'),
+    ('\U0001E935', '\U0001E913'),
+    ('\U0001E936', '\U0001E914'),
+    ('\U0001E937', '\U0001E915'),
+    ('\U0001E938', '\U0001E916'),
+    ('\U0001E939', '\U0001E917'),
+    ('\U0001E93A', '\U0001E918'),
+    ('\U0001E93B', '\U0001E919'),
+    ('\U0001E93C', '\U0001E91A'),
+    ('\U0001E93D', '\U0001E91B'),
+    ('\U0001E93E', '\U0001E91C'),
+    ('\U0001E93F', '\U0001E91D'),
+    ('\U0001E940', '\U0001E91E'),
+    ('\U0001E941', '\U0001E91F'),
+    ('\U0001E942', '\U0001E920'),
+    ('\U0001E943', '\U0001E921'),
+];
@@ -4,13 +4,20 @@
 
 use std::char;
 use std::cmp::{min, max};
+use std::fmt;
 
 
 /// A range of codepoints.
 pub type Range = (char, char);
 
 
-/// A character class is a non-empty collection of ranges.
+/// The number of ranges to show before abbreviating a class.
+static SHOW_RANGES: uint = 8;
+
+
+/// A character class is a collection of ranges.  Classes built with
+/// `new` are never empty, but negation and the set operations can
+/// produce an empty one.
 #[deriving(Clone)]
 pub enum CharClass {
     priv CCOwned(~[Range]),
@@ -36,17 +43,20 @@ impl CharClass {
             // Coalesce overlapping ranges in place
             let mut cursor = 0;
             for i in range(1, r.len()) {
-                if r[cursor].hi() == char::MAX {
-                    break
-                } else if r[i].lo() <= next_char(r[cursor].hi()) {
-                    // Merge the two ranges
-                    r[cursor] = (
-                        min(r[cursor].lo(), r[i].lo()),
-                        max(r[cursor].hi(), r[i].hi()));
-                } else {
-                    // Create a new range
-                    cursor += 1;
-                    r[cursor] = r[i];
+                match next_char(r[cursor].hi()) {
+                    // Nothing after this range, so it covers the rest
+                    None => break,
+                    Some(next) if r[i].lo() <= next => {
+                        // Merge the two ranges
+                        r[cursor] = (
+                            min(r[cursor].lo(), r[i].lo()),
+                            max(r[cursor].hi(), r[i].hi()));
+                    },
+                    Some(_) => {
+                        // Create a new range
+                        cursor += 1;
+                        r[cursor] = r[i];
+                    }
                 }
             }
             r.truncate(cursor + 1); r.shrink_to_fit();
@@ -66,7 +76,8 @@ impl CharClass {
         CharClass::new(~[(lo, hi)])
     }
 
-    /// Combine several classes into one that subsumes them all.
+    /// Combine several classes into one that subsumes them all.  If
+    /// they are all empty, so is the result.
     pub fn combine(classes: &[CharClass]) -> CharClass {
         let mut ranges: ~[Range] = ~[];
         for cc in classes.iter() {
@@ -74,7 +85,11 @@ impl CharClass {
                 ranges.push(*r);
             }
         }
-        CharClass::new(ranges)
+        if ranges.is_empty() {
+            CCOwned(ranges)
+        } else {
+            CharClass::new(ranges)
+        }
     }
 
     ///
@@ -122,24 +137,139 @@ impl CharClass {
     pub fn negate(&self) -> CharClass {
         let ranges = self.ranges();
         let mut result: ~[Range] = ~[];
+        if ranges.is_empty() {
+            result.push(('\0', char::MAX));
+            return CCOwned(result);
+        }
 
-        let first_lo = ranges[0].lo();
-        if first_lo != '\0' {
-            result.push(('\0', prev_char(first_lo)));
+        match prev_char(ranges[0].lo()) {
+            Some(c) => result.push(('\0', c)),
+            None => ()
         }
 
+        // Ranges built by `new` never touch, but the static tables can
+        // have neighbours on either side of the surrogates, with no gap
+        // between them
         let mut last_hi = ranges[0].hi();
         for &(lo, hi) in ranges.slice_from(1).iter() {
-            result.push((next_char(last_hi), prev_char(lo)));
+            match (next_char(last_hi), prev_char(lo)) {
+                (Some(gap_lo), Some(gap_hi)) if gap_lo <= gap_hi =>
+                    result.push((gap_lo, gap_hi)),
+                _ => ()
+            }
             last_hi = hi;
         }
 
-        if last_hi != char::MAX {
-            result.push((next_char(last_hi), char::MAX));
+        match next_char(last_hi) {
+            Some(c) => result.push((c, char::MAX)),
+            None => ()
+        }
+
+        CCOwned(result)
+    }
+
+    /// Return a class containing only the characters in both classes.
+    /// The result may be empty.
+    pub fn intersect(&self, other: &CharClass) -> CharClass {
+        let (a, b) = (self.ranges(), other.ranges());
+        let mut result: ~[Range] = ~[];
+
+        // Walk both lists in step, advancing whichever range ends first
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            let lo = max(a[i].lo(), b[j].lo());
+            let hi = min(a[i].hi(), b[j].hi());
+            if lo <= hi {
+                result.push((lo, hi));
+            }
+            if a[i].hi() < b[j].hi() {
+                i += 1;
+            } else {
+                j += 1;
+            }
         }
 
         CCOwned(result)
     }
+
+    /// Return a class containing the characters in this class that are
+    /// not in the other one.  The result may be empty.
+    pub fn difference(&self, other: &CharClass) -> CharClass {
+        self.intersect(&other.negate())
+    }
+
+    /// Return a class that also includes every character that is equal
+    /// to one in this class when case is ignored.
+    pub fn case_fold(&self) -> CharClass {
+        let pairs = casefold::pairs;
+        let mut ranges: ~[Range] = self.ranges().to_owned();
+        if ranges.is_empty() {
+            return CCOwned(ranges);
+        }
+
+        for &(lo, hi) in self.ranges().iter() {
+            // Binary search for the first pair inside the range
+            let (mut i, mut j) = (0, pairs.len());
+            while i < j {
+                let mid = i + (j - i) / 2;
+                let (c, _) = pairs[mid];
+                if c < lo {
+                    i = mid + 1;
+                } else {
+                    j = mid;
+                }
+            }
+
+            // Add the other half of every pair in the range
+            while i < pairs.len() {
+                let (c, other) = pairs[i];
+                if c > hi {
+                    break
+                }
+                ranges.push((other, other));
+                i += 1;
+            }
+        }
+
+        CharClass::new(ranges)
+    }
+}
+
+
+impl fmt::Show for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranges = self.ranges();
+        if ranges.is_empty() {
+            return write!(f.buf, "(empty)");
+        }
+        for (i, &(lo, hi)) in ranges.iter().take(SHOW_RANGES).enumerate() {
+            if i > 0 {
+                try!(write!(f.buf, " "));
+            }
+            try!(write!(f.buf, "{}", range_to_str(lo, hi)));
+        }
+        if ranges.len() > SHOW_RANGES {
+            try!(write!(f.buf, " ... ({} more)", ranges.len() - SHOW_RANGES));
+        }
+        Ok(())
+    }
+}
+
+
+/// Quote a range for display, e.g. `'a'-'z'`.  A range of a single code
+/// point is shown as just that code point.
+pub fn range_to_str(lo: char, hi: char) -> ~str {
+    let quote = |c: char| {
+        let mut s = ~"'";
+        c.escape_default(|e| s.push_char(e));
+        s.push_char('\'');
+        s
+    };
+    if lo == hi {
+        quote(lo)
+    } else {
+        format!("{}-{}", quote(lo), quote(hi))
+    }
 }
 
 
@@ -163,21 +293,33 @@ impl RangeUtils for (char, char) {
 }
 
 
+/// Return the code point before this one, skipping over the surrogates,
+/// or `None` if there isn't one.
 #[inline]
-fn prev_char(c: char) -> char {
-    char::from_u32(c as u32 - 1).unwrap()
+fn prev_char(c: char) -> Option<char> {
+    match c {
+        '\0' => None,
+        '\uE000' => Some('\uD7FF'),
+        _ => char::from_u32(c as u32 - 1)
+    }
 }
 
+/// Return the code point after this one, skipping over the surrogates,
+/// or `None` if there isn't one.
 #[inline]
-fn next_char(c: char) -> char {
-    char::from_u32(c as u32 + 1).unwrap()
+fn next_char(c: char) -> Option<char> {
+    match c {
+        char::MAX => None,
+        '\uD7FF' => Some('\uE000'),
+        _ => char::from_u32(c as u32 + 1)
+    }
 }
 
 
 #[cfg(test)]
 mod test {
     use std::char;
-    use super::CharClass;
+    use super::{CharClass, CCStatic};
 
     #[test]
     #[should_fail]
@@ -227,6 +369,76 @@ mod test {
         assert_eq!(c.ranges(), &[]);
     }
 
+    #[test]
+    fn surrogate_gap() {
+        let c = CharClass::new(~[('a', '\uD7FF'), ('\uE000', '\uFFFF')]);
+        assert_eq!(c.ranges(), &[('a', '\uFFFF')]);
+        let c = CharClass::new(~[('\0', '\uD7FF')]).negate();
+        assert_eq!(c.ranges(), &[('\uE000', char::MAX)]);
+        let c = CharClass::new(~[('\uE000', char::MAX)]).negate();
+        assert_eq!(c.ranges(), &[('\0', '\uD7FF')]);
+        let c = CharClass::new(~[('\0', '\uD7FF'), ('\uE000', char::MAX)]).negate();
+        assert_eq!(c.ranges(), &[]);
+    }
+
+    #[test]
+    fn negate_across_surrogates() {
+        static touching: &'static [(char, char)] = &[('a', '\uD7FF'), ('\uE000', 'z')];
+        let c = CCStatic(touching).negate();
+        assert_eq!(c.ranges(), &[('\0', '`'), ('{', char::MAX)]);
+    }
+
+    #[test]
+    fn negate_empty() {
+        let c = CharClass::new(~[('\0', char::MAX)]).negate().negate();
+        assert_eq!(c.ranges(), &[('\0', char::MAX)]);
+    }
+
+    #[test]
+    fn intersect() {
+        let c1 = CharClass::new(~[('a', 'm'), ('x', 'z')]);
+        let c2 = CharClass::new(~[('c', 'e'), ('k', 'y')]);
+        assert_eq!(c1.intersect(&c2).ranges(), &[('c', 'e'), ('k', 'm'), ('x', 'y')]);
+        let c3 = CharClass::from_range('0', '9');
+        assert_eq!(c1.intersect(&c3).ranges(), &[]);
+    }
+
+    #[test]
+    fn combine_empty() {
+        let empty = CharClass::from_char('a').intersect(&CharClass::from_char('b'));
+        assert_eq!(CharClass::combine([empty.clone()]).ranges(), &[]);
+        let c = CharClass::combine([CharClass::from_char('x'), empty]);
+        assert_eq!(c.ranges(), &[('x', 'x')]);
+    }
+
+    #[test]
+    fn difference() {
+        let c1 = CharClass::new(~[('a', 'z'), ('0', '9')]);
+        let c2 = CharClass::new(~[('c', 'e'), ('5', '5')]);
+        assert_eq!(c1.difference(&c2).ranges(),
+            &[('0', '4'), ('6', '9'), ('a', 'b'), ('f', 'z')]);
+        assert_eq!(c2.difference(&c1).ranges(), &[]);
+    }
+
+    #[test]
+    fn case_fold() {
+        let c = CharClass::new(~[('a', 'c'), ('Z', 'Z')]).case_fold();
+        assert_eq!(c.ranges(), &[('A', 'C'), ('Z', 'Z'), ('a', 'c'), ('z', 'z')]);
+        let c = CharClass::from_char('k').case_fold();
+        assert_eq!(c.ranges(), &[('K', 'K'), ('k', 'k'), ('\u212A', '\u212A')]);
+    }
+
+    #[test]
+    fn show() {
+        let c = CharClass::new(~[('a', 'z'), ('_', '_'), ('\n', '\n')]);
+        assert_eq!(format!("{}", c), ~"'\\n' '_' 'a'-'z'");
+        let c = CharClass::new(range(0u32, 10).map(|i| {
+            let c = char::from_u32('a' as u32 + 2 * i).unwrap();
+            (c, c)
+        }).collect());
+        assert_eq!(format!("{}", c), ~"'a' 'c' 'e' 'g' 'i' 'k' 'm' 'o' ... (2 more)");
+    }
+
     #[test]
     fn issue_1() {
         // See <https://github.com/lfairy/rose/issues/1>
@@ -236,3 +448,5 @@ mod test {
 }
 
 pub mod ascii;
+pub mod casefold;
+pub mod unicode;
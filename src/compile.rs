@@ -2,28 +2,59 @@
 
 use parse;
 use parse::{Expr, Greedy, NonGreedy};
-use super::Regex;
-use vm::{Inst, Jump, Range, Save};
+use super::{Regex, RegexSet};
+use vm::{Inst, Assert, Class, Jump, Match, Range, Save};
+use vm::LeftmostFirst;
 
 
 /// Compile an AST into a `Regex`.
 pub fn compile(e: &Expr) -> Regex {
     let mut p = Builder::new();
+
+    // Registers 0 and 1 hold the bounds of the whole match
+    let (open_reg, close_reg) = p.allocate(None);
+    p.push(Save(open_reg));
     compile_expr(&mut p, e);
+    p.push(Save(close_reg));
+    p.push(Match(0));
+
     p.reify()
 }
 
 
+/// Compile several ASTs into a `RegexSet`.  The program forks to every
+/// pattern at once, and each one ends in a `Match` carrying its index.
+pub fn compile_set(exprs: &[Expr]) -> RegexSet {
+    let mut p = Builder::new();
+
+    let fork = p.len(); p.push_jump();
+    let mut heads = ~[];
+    for (i, e) in exprs.iter().enumerate() {
+        heads.push(p.len());
+        compile_expr(&mut p, e);
+        p.push(Match(i));
+    }
+    p.jumps(fork).push_all_move(heads);
+
+    RegexSet {
+        program: p.program,
+        len: exprs.len()
+    }
+}
+
+
 struct Builder {
     program: ~[Inst],
-    n_regs: uint
+    n_regs: uint,
+    names: ~[Option<~str>]
 }
 
 impl Builder {
     fn new() -> Builder {
         Builder {
             program: ~[],
-            n_regs: 0
+            n_regs: 0,
+            names: ~[]
         }
     }
 
@@ -46,15 +77,21 @@ impl Builder {
         }
     }
 
-    fn allocate(&mut self) -> (uint, uint) {
+    fn allocate(&mut self, name: Option<~str>) -> (uint, uint) {
         let reg = self.n_regs;
         self.n_regs = 2u.checked_add(&self.n_regs).expect("too many capturing groups");
+        self.names.push(name);
         (reg, 1 + reg)
     }
 
     fn reify(self) -> Regex {
-        let Builder { program, n_regs } = self;
-        Regex { program: program, n_regs: n_regs }
+        let Builder { program, n_regs, names } = self;
+        Regex {
+            program: program,
+            n_regs: n_regs,
+            names: names,
+            semantics: LeftmostFirst
+        }
     }
 }
 
@@ -73,6 +110,8 @@ fn compile_expr(p: &mut Builder, e: &Expr) {
     match *e {
         parse::Empty => (),
         parse::Range(lo, hi) => p.push(Range(lo, hi)),
+        parse::Class(ref cc) => p.push(Class(cc.clone())),
+        parse::Assert(assertion) => p.push(Assert(assertion)),
         parse::Concatenate(ref inners) => {
             // Execute all children, one after the other
             for inner in inners.iter() {
@@ -99,8 +138,8 @@ fn compile_expr(p: &mut Builder, e: &Expr) {
             }
         },
         parse::Repeat(ref inner, min, max, greedy) => compile_repeat(p, *inner, min, max, greedy),
-        parse::Capture(ref inner) => {
-            let (open_reg, close_reg) = p.allocate();
+        parse::Capture(ref inner, ref name) => {
+            let (open_reg, close_reg) = p.allocate(name.clone());
             p.push(Save(open_reg));
             compile_expr(p, *inner);
             p.push(Save(close_reg));
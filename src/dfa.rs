@@ -0,0 +1,315 @@
+//!
+//! Lazily built deterministic automaton, for queries that only need to
+//! know whether a match exists, or which patterns in a set match.
+//!
+//! Each state of the automaton stands for a set of `pc`s in the same
+//! program the VM runs.  States and transitions are only worked out the
+//! first time they are needed, and are cached after that.  Since a
+//! pathological pattern can have exponentially many states, the cache
+//! is bounded; once it fills up, it is thrown away and built again from
+//! scratch.
+//!
+
+use std::vec;
+use collections::{TreeMap, TrieMap, TrieSet};
+
+use charclass::ascii;
+use charclass::unicode;
+use vm::{Inst, Assert, Class, Jump, Match, Range, Save};
+use vm::{Context, EndTextNewline};
+
+
+/// The maximum number of states kept in the cache.
+static CACHE_SIZE: uint = 1024;
+
+
+/// What the DFA needs to remember about the previous character.
+/// Assertions only look at these properties of it, so there's no need
+/// to keep the character itself.
+#[deriving(Clone, Eq, Ord, TotalEq, TotalOrd)]
+enum Prev {
+    StartOfText,
+    Newline,
+    /// A word character by both the ASCII and Unicode definitions
+    WordChar,
+    /// A word character by the Unicode definition only
+    UnicodeWordChar,
+    OtherChar
+}
+
+impl Prev {
+    /// A character with the same properties, for building a `Context`.
+    fn representative(&self) -> Option<char> {
+        match *self {
+            StartOfText => None,
+            Newline => Some('\n'),
+            WordChar => Some('a'),
+            UnicodeWordChar => Some('\xE9'),
+            OtherChar => Some(' ')
+        }
+    }
+}
+
+
+struct State {
+    /// The threads waiting to be expanded, in no particular order.
+    insts: ~[uint],
+    prev: Prev,
+    /// The patterns that matched on the way into this state.
+    matched: ~[uint],
+    /// Transitions worked out so far, keyed by character.
+    next: TrieMap<uint>
+}
+
+
+/// A lazy DFA, along with its cache of states.
+pub struct DFA {
+    priv anchored: bool,
+    /// Whether the program contains assertions.  If not, the previous
+    /// character never matters, and states can ignore it.
+    priv has_asserts: bool,
+    priv states: ~[State],
+    priv index: TreeMap<(~[uint], Prev, ~[uint]), uint>
+}
+
+impl DFA {
+    ///
+    /// Create a DFA for the given program.  If `anchored` is `true`,
+    /// it only matches at the start of the input.
+    ///
+    /// Return `None` if the program can't be run this way.  This
+    /// happens when it uses `\Z`, which needs to look two characters
+    /// ahead.
+    ///
+    pub fn new(program: &[Inst], anchored: bool) -> Option<DFA> {
+        let mut has_asserts = false;
+        for inst in program.iter() {
+            match *inst {
+                Assert(EndTextNewline) => return None,
+                Assert(..) => has_asserts = true,
+                _ => ()
+            }
+        }
+
+        Some(DFA {
+            anchored: anchored,
+            has_asserts: has_asserts,
+            states: ~[],
+            index: TreeMap::new()
+        })
+    }
+
+    /// Return `true` if the program matches the input.  The program
+    /// must be the one this DFA was created with.
+    pub fn is_match(&mut self, program: &[Inst], input: &str) -> bool {
+        let mut current = self.add_state(~[0], StartOfText, ~[]);
+        for c in input.chars() {
+            if self.anchored && self.states[current].insts.is_empty() {
+                // Nothing left that could match
+                return false;
+            }
+            current = self.transition(program, current, c);
+            if !self.states[current].matched.is_empty() {
+                return true;
+            }
+        }
+
+        let (_, matched) = self.expand_at_end(program, current);
+        !matched.is_empty()
+    }
+
+    ///
+    /// Return every pattern in a set that matches the input, in order.
+    /// `n` is the number of patterns.
+    ///
+    /// Patterns are told apart by the ids in their `Match` instructions,
+    /// which must be less than `n`.
+    ///
+    pub fn which_match(&mut self, program: &[Inst], input: &str, n: uint) -> ~[uint] {
+        let mut seen = vec::from_elem(n, false);
+        let mut remaining = n;
+        let mut current = self.add_state(~[0], StartOfText, ~[]);
+        for c in input.chars() {
+            if remaining == 0 || self.anchored && self.states[current].insts.is_empty() {
+                break
+            }
+            current = self.transition(program, current, c);
+            for &id in self.states[current].matched.iter() {
+                if !seen[id] {
+                    seen[id] = true;
+                    remaining -= 1;
+                }
+            }
+        }
+
+        let (_, matched) = self.expand_at_end(program, current);
+        for &id in matched.iter() {
+            seen[id] = true;
+        }
+        range(0, n).filter(|&id| seen[id]).collect()
+    }
+
+    /// Expand a state at the end of the input.
+    fn expand_at_end(&self, program: &[Inst], current: uint) -> (~[uint], ~[uint]) {
+        let cx = Context {
+            prev: self.states[current].prev.representative(),
+            next: None,
+            after: None
+        };
+        expand(program, self.states[current].insts.as_slice(), &cx)
+    }
+
+    /// Work out where the given character leads from a state, using the
+    /// cached transition if there is one.
+    fn transition(&mut self, program: &[Inst], current: uint, c: char) -> uint {
+        match self.states[current].next.find(&(c as uint)) {
+            Some(&next) => return next,
+            None => ()
+        }
+
+        let cx = Context {
+            prev: self.states[current].prev.representative(),
+            next: Some(c),
+            after: None
+        };
+        let (rest, matched) = expand(program, self.states[current].insts.as_slice(), &cx);
+        let mut insts = advance(program, rest, c);
+        if !self.anchored {
+            insts.push(0);
+        }
+        insts.sort();
+        insts.dedup();
+
+        let before = self.states.len();
+        let prev = self.prev_of(c);
+        let next = self.add_state(insts, prev, matched);
+
+        // If the cache was flushed, the current state is gone
+        if self.states.len() >= before {
+            self.states[current].next.insert(c as uint, next);
+        }
+        next
+    }
+
+    /// Find the state with the given threads, creating it if necessary.
+    /// Flushes the cache if it's full.
+    fn add_state(&mut self, insts: ~[uint], prev: Prev, matched: ~[uint]) -> uint {
+        let key = (insts, prev, matched);
+        match self.index.find(&key) {
+            Some(&i) => return i,
+            None => ()
+        }
+
+        if self.states.len() >= CACHE_SIZE {
+            self.states.clear();
+            self.index.clear();
+        }
+
+        let i = self.states.len();
+        let (insts, prev, matched) = key;
+        self.states.push(State {
+            insts: insts.clone(),
+            prev: prev,
+            matched: matched.clone(),
+            next: TrieMap::new()
+        });
+        self.index.insert((insts, prev, matched), i);
+        i
+    }
+
+    /// Classify a character for use as the previous one.
+    fn prev_of(&self, c: char) -> Prev {
+        if !self.has_asserts {
+            OtherChar
+        } else if c == '\n' {
+            Newline
+        } else if ascii::word.includes(c) {
+            WordChar
+        } else if unicode::word.includes(c) {
+            UnicodeWordChar
+        } else {
+            OtherChar
+        }
+    }
+}
+
+
+///
+/// Run a program over the input without building a DFA, and return
+/// every pattern that matches, in order.
+///
+/// This is slower than `DFA::which_match`, since nothing is cached, but
+/// it sees the real characters around each position and so handles
+/// every kind of assertion.
+///
+pub fn simulate(program: &[Inst], input: &str) -> ~[uint] {
+    let mut seen = TrieSet::new();
+    let mut insts = ~[0u];
+    let mut prev = None;
+    let mut chars = input.chars().peekable();
+    loop {
+        let next = chars.next();
+        let cx = Context { prev: prev, next: next, after: chars.peek().map(|&c| c) };
+        let (rest, matched) = expand(program, insts, &cx);
+        for &id in matched.iter() {
+            seen.insert(id);
+        }
+        match next {
+            Some(c) => {
+                insts = advance(program, rest, c);
+                insts.push(0);
+                prev = Some(c);
+            },
+            None => break
+        }
+    }
+    seen.iter().collect()
+}
+
+
+///
+/// Follow the given threads until they all come to rest on a `Range` or
+/// `Class` instruction, and return the `pc`s they end up at.
+///
+/// Also return the ids of the patterns that matched along the way.
+///
+fn expand(program: &[Inst], insts: &[uint], cx: &Context) -> (~[uint], ~[uint]) {
+    let mut seen = TrieSet::new();
+    let mut stack = insts.to_owned();
+    let mut rest = ~[];
+    let mut matched = ~[];
+    loop {
+        let pc = match stack.pop() {
+            Some(pc) => pc,
+            None => break
+        };
+        if !seen.insert(pc) {
+            continue
+        }
+        match program[pc] {
+            Jump(ref exits) => stack.push_all(*exits),
+            Save(..) => stack.push(1 + pc),
+            Assert(ref assertion) => if assertion.holds(cx) {
+                stack.push(1 + pc);
+            },
+            Match(id) => matched.push(id),
+            Range(..) | Class(..) => rest.push(pc)
+        }
+    }
+    rest.sort();
+    matched.sort();
+    (rest, matched)
+}
+
+
+/// Run the threads resting on `Range` and `Class` instructions over a
+/// character, and return the `pc`s of the ones that survive.
+fn advance(program: &[Inst], rest: ~[uint], c: char) -> ~[uint] {
+    rest.move_iter().filter(|&pc| {
+        match program[pc] {
+            Range(lo, hi) => lo <= c && c <= hi,
+            Class(ref cc) => cc.includes(c),
+            Jump(..) | Save(..) | Assert(..) | Match(..) => unreachable!()
+        }
+    }).map(|pc| 1 + pc).collect()
+}
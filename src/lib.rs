@@ -6,7 +6,7 @@
 //! ~~~
 //! extern crate rose;
 //!
-//! let names = rose::compile(r"Bon Bon|Lyra");
+//! let names = rose::compile(r"Bon Bon|Lyra").unwrap();
 //! match names.exec("Lyra Heartstrings") {
 //!     Some(result) => println!("Found: {}", result.group(0)),
 //!     None => fail!("Oh noes!")
@@ -22,41 +22,1263 @@
 //!
 //! To get started, prepare a pattern using [compile](fn.compile.html).
 //! This creates a [Regex](struct.Regex.html) object, which can then be
-//! run using its `exec` and `search` methods.
+//! run using its `exec` and `search` methods.  Invalid patterns are
+//! reported as a [parse::Error](parse/struct.Error.html).
 //!
 
 #[feature(macro_rules)];
 
 extern crate collections = "collections#0.10-pre";
+#[cfg(test)] extern crate sync = "sync#0.10-pre";
 
+use std::fmt;
+use std::io;
+use std::io::IoResult;
+
+pub mod backtrack;
 pub mod compile;
+pub mod dfa;
 pub mod parse;
+pub mod stream;
 pub mod vm;
-mod charclass;
+pub mod charclass;
 
 /// A compiled regular expression.  Use [compile](fn.compile.html) to
 /// create one of these.
 pub struct Regex {
     priv program: ~[vm::Inst],
-    priv n_regs: uint
+    priv n_regs: uint,
+    priv names: ~[Option<~str>],
+    priv semantics: vm::MatchSemantics
 }
 
 impl Regex {
-    /// Check if the regex matches the given string.
+    ///
+    /// Check if the regex matches the start of the given string.
+    ///
+    /// This and `is_match` run a lazy DFA, which is built afresh for
+    /// each call so that the regex itself is never modified.
+    ///
     pub fn matches(&self, s: &str) -> bool {
+        match dfa::DFA::new(self.program, true) {
+            Some(mut dfa) => return dfa.is_match(self.program, s),
+            None => ()
+        }
+
         let mut vm = vm::VM::new(self.program);
         for c in s.chars() {
+            if vm.is_match() || vm.is_done() {
+                break
+            }
             vm.feed(c);
-            if vm.is_match() {
-                return true;
+        }
+        vm.finish();
+        vm.is_match()
+    }
+
+    /// Check if the regex matches anywhere in the given string.  This
+    /// is faster than `search` when the bounds aren't needed.
+    pub fn is_match(&self, s: &str) -> bool {
+        match dfa::DFA::new(self.program, false) {
+            Some(mut dfa) => dfa.is_match(self.program, s),
+            None => self.search(s).is_some()
+        }
+    }
+
+    ///
+    /// Match the regex against the start of the given string.
+    ///
+    /// On success, return the text and position of every capturing
+    /// group.  Group 0 always refers to the whole match.
+    ///
+    pub fn exec<'t>(&'t self, s: &'t str) -> Option<Captures<'t>> {
+        self.run(s, true)
+    }
+
+    ///
+    /// Find the leftmost match anywhere in the given string.
+    ///
+    /// Like `exec`, this returns every capturing group on success.  The
+    /// bounds of the match itself are given by `pos(0)`.
+    ///
+    pub fn search<'t>(&'t self, s: &'t str) -> Option<Captures<'t>> {
+        self.run(s, false)
+    }
+
+    ///
+    /// Iterate over the bounds of every non-overlapping match in the
+    /// given string.
+    ///
+    /// An empty match directly after the previous match is skipped, so
+    /// `a*` finds `(0, 1)` and `(2, 3)` in `"aXa"`.
+    ///
+    pub fn find_iter<'t>(&'t self, s: &'t str) -> FindMatches<'t> {
+        FindMatches { inner: self.captures_iter(s) }
+    }
+
+    /// Iterate over the capturing groups of every non-overlapping match
+    /// in the given string.  See `find_iter` for details.
+    pub fn captures_iter<'t>(&'t self, s: &'t str) -> FindCaptures<'t> {
+        FindCaptures {
+            regex: self,
+            text: s,
+            vm: vm::VM::unanchored(self.program).with_semantics(self.semantics),
+            pos: Some(0),
+            last_end: None
+        }
+    }
+
+    ///
+    /// Iterate over the substrings of the given string that lie
+    /// between matches.
+    ///
+    /// A match at the very start or end of the string produces an empty
+    /// substring there, so this always yields at least one item.
+    ///
+    /// ~~~
+    /// let re = rose::compile(r",\s*").unwrap();
+    /// let fields: ~[&str] = re.split("a, b,c,").collect();
+    /// assert_eq!(fields, ~["a", "b", "c", ""]);
+    /// ~~~
+    ///
+    pub fn split<'t>(&'t self, s: &'t str) -> Splits<'t> {
+        Splits {
+            finder: self.find_iter(s),
+            text: s,
+            last: Some(0)
+        }
+    }
+
+    /// Like `split`, but yield at most `limit` substrings.  The last
+    /// one holds the rest of the string, matches and all.
+    pub fn splitn<'t>(&'t self, s: &'t str, limit: uint) -> SplitsN<'t> {
+        SplitsN {
+            splits: self.split(s),
+            limit: limit
+        }
+    }
+
+    ///
+    /// Replace the leftmost match in the given string with a template.
+    ///
+    /// See `Captures::expand` for the template syntax.
+    ///
+    pub fn replace(&self, s: &str, template: &str) -> ~str {
+        self.replace_with(s, |caps| caps.expand(template))
+    }
+
+    /// Replace every non-overlapping match in the given string with a
+    /// template.  See `Captures::expand` for the template syntax.
+    pub fn replace_all(&self, s: &str, template: &str) -> ~str {
+        self.replace_all_with(s, |caps| caps.expand(template))
+    }
+
+    /// Replace the leftmost match in the given string with the result
+    /// of calling the closure on it.
+    pub fn replace_with(&self, s: &str, f: |&Captures| -> ~str) -> ~str {
+        self.replace_n(s, 1, f)
+    }
+
+    /// Replace every non-overlapping match in the given string with the
+    /// result of calling the closure on it.
+    pub fn replace_all_with(&self, s: &str, f: |&Captures| -> ~str) -> ~str {
+        self.replace_n(s, 0, f)
+    }
+
+    /// Replace up to `limit` matches, or all of them if `limit` is 0.
+    fn replace_n(&self, s: &str, limit: uint, f: |&Captures| -> ~str) -> ~str {
+        let mut result = std::str::with_capacity(s.len());
+        let mut last_end = 0;
+        for (i, caps) in self.captures_iter(s).enumerate() {
+            if limit > 0 && i >= limit {
+                break
             }
+            let (start, end) = caps.pos(0).unwrap();
+            result.push_str(s.slice(last_end, start));
+            result.push_str(f(&caps));
+            last_end = end;
+        }
+        result.push_str(s.slice_from(last_end));
+        result
+    }
+
+    /// Run the regex over the given string, using the backtracker if
+    /// the input is small enough and the VM otherwise.
+    fn run<'t>(&'t self, s: &'t str, anchored: bool) -> Option<Captures<'t>> {
+        // The backtracker only knows about leftmost-first matching
+        if self.semantics == vm::LeftmostFirst && backtrack::should_use(self.program, s.len()) {
+            return backtrack::run(self.program, self.n_regs, s, anchored)
+                .map(|registers| Captures::new(s, registers, self.names));
+        }
+
+        let mut vm = if anchored {
+            vm::VM::new(self.program)
+        } else {
+            vm::VM::unanchored(self.program)
+        }.with_semantics(self.semantics);
+        for c in s.chars() {
+            if vm.is_done() {
+                break
+            }
+            vm.feed(c);
+        }
+        vm.finish();
+        vm.registers().map(|registers| Captures::new(s, registers, self.names))
+    }
+
+    ///
+    /// Start searching a stream of UTF-8 text, which can then be fed in
+    /// a chunk at a time.
+    ///
+    /// ~~~
+    /// let re = rose::compile(r"\d+").unwrap();
+    /// let mut stream = re.stream();
+    /// let mut found = stream.feed("a 12".as_bytes()).unwrap();
+    /// found.push_all(stream.feed("34 b".as_bytes()).unwrap());
+    /// found.push_all(stream.finish().unwrap());
+    /// assert_eq!(found, ~[(2, 6)]);
+    /// ~~~
+    ///
+    pub fn stream<'r>(&'r self) -> stream::Stream<'r> {
+        stream::Stream::new(self)
+    }
+
+    ///
+    /// Search everything that can be read from a `Reader`, calling `f`
+    /// with the bounds of each match as it is found.
+    ///
+    /// The bounds are byte offsets from where the reader started.  Only
+    /// as much of the input as the search needs is held in memory.  If
+    /// `f` returns `false`, the search stops early.
+    ///
+    pub fn find_reader<R: Reader>(&self, reader: &mut R, f: |(u64, u64)| -> bool)
+            -> IoResult<()> {
+        let mut stream = self.stream();
+        let mut buf = std::vec::from_elem(64 * 1024, 0u8);
+        loop {
+            let n = match reader.read(buf.as_mut_slice()) {
+                Ok(n) => n,
+                Err(ref e) if e.kind == io::EndOfFile => break,
+                Err(e) => return Err(e)
+            };
+            for &m in try!(stream.feed(buf.slice_to(n))).iter() {
+                if !f(m) {
+                    return Ok(());
+                }
+            }
+        }
+        for &m in try!(stream.finish()).iter() {
+            if !f(m) {
+                break
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Disassemble the compiled program, one numbered instruction per
+    /// line.  Instructions that save the bounds of a group say which
+    /// group it is.
+    ///
+    /// ~~~
+    /// let re = rose::compile("[a-z]+").unwrap();
+    /// assert_eq!(re.dump_program(), ~"0: save 0 (group 0 start)\n\
+    ///                                 1: range 'a'-'z'\n\
+    ///                                 2: split 1, 3\n\
+    ///                                 3: save 1 (group 0 end)\n\
+    ///                                 4: match 0\n");
+    /// ~~~
+    ///
+    pub fn dump_program(&self) -> ~str {
+        let mut out = ~"";
+        for (pc, inst) in self.program.iter().enumerate() {
+            out.push_str(format!("{}: {}", pc, *inst));
+            match *inst {
+                vm::Save(reg) => {
+                    let (group, side) = (reg / 2, if reg % 2 == 0 { "start" } else { "end" });
+                    out.push_str(match self.names[group] {
+                        Some(ref name) => format!(" (group {} <{}> {})", group, *name, side),
+                        None => format!(" (group {} {})", group, side)
+                    });
+                },
+                _ => ()
+            }
+            out.push_char('\n');
+        }
+        out
+    }
+
+    ///
+    /// Iterate over the names of the capturing groups, in order.
+    ///
+    /// Unnamed groups, including the implicit group 0, are yielded as
+    /// `None`.
+    ///
+    pub fn capture_names<'r>(&'r self) -> CaptureNames<'r> {
+        CaptureNames { names: self.names.iter() }
+    }
+}
+
+
+impl fmt::Show for Regex {
+    /// Show the disassembled program.  See `Regex::dump_program`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f.buf, "{}", self.dump_program())
+    }
+}
+
+
+///
+/// A set of regular expressions that are matched in a single pass.  Use
+/// [compile_set](fn.compile_set.html) to create one of these.
+///
+/// ~~~
+/// let set = rose::compile_set([r"\d+", r"^\w+$", "x"]).unwrap();
+/// assert_eq!(set.matches("abc123"), ~[0, 1]);
+/// ~~~
+///
+pub struct RegexSet {
+    priv program: ~[vm::Inst],
+    priv len: uint
+}
+
+impl RegexSet {
+    /// Return the indices of the patterns that match anywhere in the
+    /// given string, in ascending order.
+    pub fn matches(&self, s: &str) -> ~[uint] {
+        match dfa::DFA::new(self.program, false) {
+            Some(mut dfa) => dfa.which_match(self.program, s, self.len),
+            None => dfa::simulate(self.program, s)
+        }
+    }
+
+    /// Check if any of the patterns match anywhere in the given string.
+    pub fn is_match(&self, s: &str) -> bool {
+        match dfa::DFA::new(self.program, false) {
+            Some(mut dfa) => dfa.is_match(self.program, s),
+            None => !dfa::simulate(self.program, s).is_empty()
+        }
+    }
+
+    /// Return the number of patterns in the set.
+    pub fn len(&self) -> uint {
+        self.len
+    }
+}
+
+
+///
+/// An iterator over the capturing groups of successive matches.  Use
+/// `Regex::captures_iter` to get one of these.
+///
+/// A single VM is kept for the whole iteration.  After each match it is
+/// restarted where the match ended, rather than searching again from
+/// scratch.
+///
+pub struct FindCaptures<'t> {
+    priv regex: &'t Regex,
+    priv text: &'t str,
+    priv vm: vm::VM<'t>,
+    priv pos: Option<uint>,  // The next character to feed in; `None` when finished
+    priv last_end: Option<uint>
+}
+
+impl<'t> Iterator<Captures<'t>> for FindCaptures<'t> {
+    fn next(&mut self) -> Option<Captures<'t>> {
+        let len = self.text.len();
+        loop {
+            let mut pos = match self.pos {
+                Some(pos) => pos,
+                None => return None
+            };
+
+            while pos < len && !self.vm.is_done() {
+                let r = self.text.char_range_at(pos);
+                self.vm.feed(r.ch);
+                pos = r.next;
+            }
+            if !self.vm.is_done() {
+                // Ran out of input
+                self.vm.finish();
+            }
+
+            let caps = match self.vm.registers() {
+                Some(registers) => Captures::new(self.text, registers, self.regex.names),
+                None => { self.pos = None; return None }
+            };
+
+            // Carry on from the end of the match.  An empty match at the
+            // end of the previous one is skipped, by moving forward a
+            // character and trying again.
+            let (start, end) = caps.pos(0).unwrap();
+            let skip = start == end && Some(end) == self.last_end;
+            let next = if !skip {
+                end
+            } else if end < len {
+                self.text.char_range_at(end).next
+            } else {
+                self.pos = None;
+                return None;
+            };
+            let prev = if next > 0 {
+                Some(self.text.char_range_at_reverse(next).ch)
+            } else {
+                None
+            };
+            self.vm.restart(next as u64, prev);
+            self.pos = Some(next);
+
+            if !skip {
+                self.last_end = Some(end);
+                return Some(caps);
+            }
+        }
+    }
+}
+
+
+/// An iterator over the bounds of successive matches.  Use
+/// `Regex::find_iter` to get one of these.
+pub struct FindMatches<'t> {
+    priv inner: FindCaptures<'t>
+}
+
+impl<'t> Iterator<(uint, uint)> for FindMatches<'t> {
+    fn next(&mut self) -> Option<(uint, uint)> {
+        self.inner.next().map(|caps| caps.pos(0).unwrap())
+    }
+}
+
+
+/// An iterator over the substrings between matches.  Use `Regex::split`
+/// to get one of these.
+pub struct Splits<'t> {
+    priv finder: FindMatches<'t>,
+    priv text: &'t str,
+    priv last: Option<uint>  // Start of the next substring; `None` when finished
+}
+
+impl<'t> Iterator<&'t str> for Splits<'t> {
+    fn next(&mut self) -> Option<&'t str> {
+        let last = match self.last {
+            Some(last) => last,
+            None => return None
+        };
+        match self.finder.next() {
+            Some((start, end)) => {
+                self.last = Some(end);
+                Some(self.text.slice(last, start))
+            },
+            None => {
+                self.last = None;
+                Some(self.text.slice_from(last))
+            }
+        }
+    }
+}
+
+
+/// An iterator over at most a fixed number of substrings between
+/// matches.  Use `Regex::splitn` to get one of these.
+pub struct SplitsN<'t> {
+    priv splits: Splits<'t>,
+    priv limit: uint
+}
+
+impl<'t> Iterator<&'t str> for SplitsN<'t> {
+    fn next(&mut self) -> Option<&'t str> {
+        match self.limit {
+            0 => None,
+            1 => {
+                // Return the rest of the string, without splitting it
+                self.limit = 0;
+                let text = self.splits.text;
+                self.splits.last.take().map(|last| text.slice_from(last))
+            },
+            _ => {
+                self.limit -= 1;
+                self.splits.next()
+            }
+        }
+    }
+}
+
+
+/// An iterator over the group names of a `Regex`.
+pub struct CaptureNames<'r> {
+    priv names: std::vec::Items<'r, Option<~str>>
+}
+
+impl<'r> Iterator<Option<&'r str>> for CaptureNames<'r> {
+    fn next(&mut self) -> Option<Option<&'r str>> {
+        self.names.next().map(|name| name.as_ref().map(|name| name.as_slice()))
+    }
+}
+
+
+/// The result of a successful match.  Use `Regex::exec` or
+/// `Regex::search` to get one of these.
+pub struct Captures<'t> {
+    priv text: &'t str,
+    priv locs: ~[Option<(uint, uint)>],
+    priv names: &'t [Option<~str>]
+}
+
+impl<'t> Captures<'t> {
+    fn new(text: &'t str, registers: &[Option<u64>], names: &'t [Option<~str>]) -> Captures<'t> {
+        let lookup = |reg: uint| -> Option<uint> {
+            if reg < registers.len() {
+                registers[reg].map(|n| n as uint)
+            } else {
+                None
+            }
+        };
+
+        let locs = range(0, names.len()).map(|group| {
+            match (lookup(2 * group), lookup(2 * group + 1)) {
+                (Some(start), Some(end)) => Some((start, end)),
+                _ => None
+            }
+        }).collect();
+
+        Captures { text: text, locs: locs, names: names }
+    }
+
+    ///
+    /// Return the text matched by the `n`th group, or the empty string
+    /// if that group did not participate in the match.
+    ///
+    /// Fails if there is no group with that index.
+    ///
+    pub fn group(&self, n: uint) -> &'t str {
+        match self.locs[n] {
+            Some((start, end)) => self.text.slice(start, end),
+            None => ""
+        }
+    }
+
+    ///
+    /// Return the start and end byte offsets of the `n`th group, or
+    /// `None` if that group did not participate in the match.
+    ///
+    /// Fails if there is no group with that index.
+    ///
+    pub fn pos(&self, n: uint) -> Option<(uint, uint)> {
+        self.locs[n]
+    }
+
+    ///
+    /// Return the text matched by the group with the given name, or the
+    /// empty string if that group did not participate in the match.
+    ///
+    /// If there is no group with that name, the empty string is
+    /// returned as well.
+    ///
+    pub fn name(&self, name: &str) -> &'t str {
+        match self.names.iter().position(|n| n.as_ref().map_or(false, |n| n.as_slice() == name)) {
+            Some(n) => self.group(n),
+            None => ""
+        }
+    }
+
+    ///
+    /// Substitute the groups of this match into a template.
+    ///
+    /// In the template, `$N` and `${N}` are replaced with the text of
+    /// group `N`, and `${name}` with the text of the named group.  `$$`
+    /// stands for a literal dollar sign.  References to groups that do
+    /// not exist are replaced with the empty string.
+    ///
+    /// ~~~
+    /// let re = rose::compile(r"(?P<last>\w+), (\w+)").unwrap();
+    /// let caps = re.exec("Sparkle, Twilight").unwrap();
+    /// assert_eq!(caps.expand("$2 ${last}"), ~"Twilight Sparkle");
+    /// ~~~
+    ///
+    pub fn expand(&self, template: &str) -> ~str {
+        let mut result = std::str::with_capacity(template.len());
+        let mut rest = template;
+        loop {
+            let i = match rest.find('$') {
+                Some(i) => i,
+                None => break
+            };
+            result.push_str(rest.slice_to(i));
+            rest = rest.slice_from(i + 1);
+
+            if rest.starts_with("$") {
+                // $$
+                result.push_char('$');
+                rest = rest.slice_from(1);
+            } else if rest.starts_with("{") && rest.contains_char('}') {
+                // ${N} or ${name}
+                let j = rest.find('}').unwrap();
+                result.push_str(self.lookup(rest.slice(1, j)));
+                rest = rest.slice_from(j + 1);
+            } else {
+                // $N
+                let j = rest.find(|c: char| c < '0' || '9' < c).unwrap_or(rest.len());
+                if j > 0 {
+                    result.push_str(self.lookup(rest.slice_to(j)));
+                } else {
+                    result.push_char('$');
+                }
+                rest = rest.slice_from(j);
+            }
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// Return the text of a group given either its index or its name.
+    fn lookup(&self, key: &str) -> &'t str {
+        match from_str::<uint>(key) {
+            Some(n) if n < self.len() => self.group(n),
+            Some(_) => "",
+            None => self.name(key)
+        }
+    }
+
+    /// Return the number of groups, including the implicit group 0.
+    pub fn len(&self) -> uint {
+        self.locs.len()
+    }
+
+    /// Iterate over the text matched by each group, in order.
+    pub fn iter<'a>(&'a self) -> Groups<'a, 't> {
+        Groups { captures: self, index: 0 }
+    }
+}
+
+
+/// An iterator over the groups in a `Captures`.
+pub struct Groups<'a, 't> {
+    priv captures: &'a Captures<'t>,
+    priv index: uint
+}
+
+impl<'a, 't> Iterator<&'t str> for Groups<'a, 't> {
+    fn next(&mut self) -> Option<&'t str> {
+        if self.index < self.captures.len() {
+            let text = self.captures.group(self.index);
+            self.index += 1;
+            Some(text)
+        } else {
+            None
         }
-        false
     }
 }
 
 
-/// Compile a regular expression.  Fails on invalid syntax.
-pub fn compile(regex: &str) -> Regex {
-    compile::compile(&parse::parse(regex))
+///
+/// Compiles a regular expression with custom settings.
+///
+/// ~~~
+/// use rose::RegexBuilder;
+///
+/// let re = RegexBuilder::new("^rose$").case_insensitive(true).multi_line(true)
+///     .compile().unwrap();
+/// assert!(re.search("Daisy\nRose\nLily").is_some());
+/// ~~~
+///
+pub struct RegexBuilder<'a> {
+    priv pattern: &'a str,
+    priv flags: parse::Flags,
+    priv semantics: vm::MatchSemantics
+}
+
+impl<'a> RegexBuilder<'a> {
+    /// Start building a regex from the given pattern, with every flag
+    /// turned off.
+    pub fn new(pattern: &'a str) -> RegexBuilder<'a> {
+        RegexBuilder {
+            pattern: pattern,
+            flags: parse::Flags::new(),
+            semantics: vm::LeftmostFirst
+        }
+    }
+
+    /// Match letters regardless of case.  Equivalent to `(?i)`.
+    pub fn case_insensitive(mut self, yes: bool) -> RegexBuilder<'a> {
+        self.flags.case_insensitive = yes;
+        self
+    }
+
+    /// Make `^` and `$` match at the start and end of every line.
+    /// Equivalent to `(?m)`.
+    pub fn multi_line(mut self, yes: bool) -> RegexBuilder<'a> {
+        self.flags.multi_line = yes;
+        self
+    }
+
+    /// Allow `.` to match a newline.  Equivalent to `(?s)`.
+    pub fn dot_all(mut self, yes: bool) -> RegexBuilder<'a> {
+        self.flags.dot_all = yes;
+        self
+    }
+
+    /// Ignore whitespace and `#` comments in the pattern.  Equivalent
+    /// to `(?x)`.
+    pub fn extended(mut self, yes: bool) -> RegexBuilder<'a> {
+        self.flags.extended = yes;
+        self
+    }
+
+    /// Use the Unicode definitions of `\d`, `\s` and `\w`, rather than
+    /// the ASCII ones.  Equivalent to `(?u)`.
+    pub fn unicode(mut self, yes: bool) -> RegexBuilder<'a> {
+        self.flags.unicode = yes;
+        self
+    }
+
+    ///
+    /// Choose which match to report when several are possible.  The
+    /// default, `LeftmostFirst`, follows Perl; `LeftmostLongest` follows
+    /// POSIX; and `Earliest` stops as soon as anything matches.
+    ///
+    /// ~~~
+    /// use rose::RegexBuilder;
+    /// use rose::vm::LeftmostLongest;
+    ///
+    /// let re = RegexBuilder::new("a|ab").semantics(LeftmostLongest).compile().unwrap();
+    /// assert_eq!(re.search("abc").unwrap().group(0), "ab");
+    /// ~~~
+    ///
+    pub fn semantics(mut self, semantics: vm::MatchSemantics) -> RegexBuilder<'a> {
+        self.semantics = semantics;
+        self
+    }
+
+    /// Compile the regular expression, returning an error on invalid
+    /// syntax.
+    pub fn compile(&self) -> Result<Regex, parse::Error> {
+        parse::parse_with_flags(self.pattern, self.flags).map(|e| {
+            let mut re = compile::compile(&e);
+            re.semantics = self.semantics;
+            re
+        })
+    }
+}
+
+
+/// Compile a regular expression, returning an error on invalid syntax.
+pub fn compile(regex: &str) -> Result<Regex, parse::Error> {
+    RegexBuilder::new(regex).compile()
+}
+
+
+/// Compile several regular expressions into a `RegexSet`, returning the
+/// first error on invalid syntax.
+pub fn compile_set(regexes: &[&str]) -> Result<RegexSet, parse::Error> {
+    let mut exprs = ~[];
+    for regex in regexes.iter() {
+        exprs.push(try!(parse::parse(*regex)));
+    }
+    Ok(compile::compile_set(exprs))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{compile, compile_set, RegexBuilder};
+
+    #[test]
+    fn exec_groups() {
+        let re = compile(r"(\w+) (\w+)").unwrap();
+        let caps = re.exec("Twilight Sparkle!").expect("no match");
+        assert_eq!(caps.group(0), "Twilight Sparkle");
+        assert_eq!(caps.group(1), "Twilight");
+        assert_eq!(caps.group(2), "Sparkle");
+        assert_eq!(caps.pos(2), Some((9, 16)));
+    }
+
+    #[test]
+    fn exec_anchored() {
+        assert!(compile("b").unwrap().exec("ab").is_none());
+    }
+
+    #[test]
+    fn exec_unmatched_group() {
+        let caps = compile("(a)|(b)").unwrap().exec("b").expect("no match");
+        assert_eq!(caps.pos(1), None);
+        assert_eq!(caps.group(1), "");
+        assert_eq!(caps.group(2), "b");
+    }
+
+    #[test]
+    fn exec_multibyte() {
+        let caps = compile("é(.)").unwrap().exec("éñ").expect("no match");
+        assert_eq!(caps.pos(1), Some((2, 4)));
+        assert_eq!(caps.group(1), "ñ");
+    }
+
+    #[test]
+    fn exec_iter() {
+        let caps = compile("(a)(b)?").unwrap().exec("ac").expect("no match");
+        let groups: ~[&str] = caps.iter().collect();
+        assert_eq!(groups, ~["a", "a", ""]);
+    }
+
+    #[test]
+    fn exec_empty() {
+        let caps = compile("a*").unwrap().exec("").expect("no match");
+        assert_eq!(caps.pos(0), Some((0, 0)));
+        assert!(compile("a*").unwrap().matches(""));
+    }
+
+    #[test]
+    fn search_leftmost() {
+        let caps = compile("b+|c").unwrap().search("acbbc").expect("no match");
+        assert_eq!(caps.pos(0), Some((1, 2)));
+        let caps = compile("c|b+").unwrap().search("abbc").expect("no match");
+        assert_eq!(caps.pos(0), Some((1, 3)));
+    }
+
+    #[test]
+    fn search_groups() {
+        let caps = compile(r"(\d+)-(\d+)").unwrap().search("from 1984-2014!").expect("no match");
+        assert_eq!(caps.group(0), "1984-2014");
+        assert_eq!(caps.pos(2), Some((10, 14)));
+    }
+
+    #[test]
+    fn search_empty() {
+        assert_eq!(compile("x*").unwrap().search("abc").unwrap().pos(0), Some((0, 0)));
+        assert!(compile("z").unwrap().search("abc").is_none());
+    }
+
+    #[test]
+    fn anchors() {
+        let re = compile("^a|b$").unwrap();
+        assert_eq!(re.search("bab").unwrap().pos(0), Some((2, 3)));
+        assert_eq!(re.search("abb").unwrap().pos(0), Some((0, 1)));
+        assert!(re.search("bba").is_none());
+        assert_eq!(compile("$").unwrap().search("ab").unwrap().pos(0), Some((2, 2)));
+    }
+
+    #[test]
+    fn text_anchors() {
+        assert!(compile(r"\Aa").unwrap().search("ba").is_none());
+        assert!(compile(r"a\z").unwrap().search("a\n").is_none());
+        assert_eq!(compile(r"a\Z").unwrap().search("a\n").unwrap().pos(0), Some((0, 1)));
+        assert!(compile(r"a\Z").unwrap().search("a\n\n").is_none());
+    }
+
+    #[test]
+    fn is_match() {
+        assert!(compile(r"\d+").unwrap().is_match("abc123"));
+        assert!(!compile(r"\d+").unwrap().is_match("abc"));
+        assert!(compile(r"\bfoo$").unwrap().is_match("a foo"));
+        assert!(!compile(r"\bfoo$").unwrap().is_match("afoo"));
+        assert!(compile(r"^$").unwrap().is_match(""));
+        // `\Z` falls back to the VM
+        assert!(compile(r"a\Z").unwrap().is_match("ba\n"));
+        assert!(!compile(r"a\Z").unwrap().is_match("ba\n\n"));
+    }
+
+    #[test]
+    fn dfa_cache_flush() {
+        // This needs far more states than fit in the cache
+        let re = compile("[ab]*a[ab]{12}c").unwrap();
+        let mut seed = 12345u32;
+        for _ in range(0, 20) {
+            let mut s = ~"";
+            for _ in range(0, 2000) {
+                seed = seed * 1103515245 + 12345;
+                s.push_char(if (seed >> 16) & 1 == 0 { 'a' } else { 'b' });
+            }
+            s.push_char('c');
+            assert_eq!(re.matches(s), re.exec(s).is_some());
+        }
+    }
+
+    #[test]
+    fn shared_between_tasks() {
+        use std::task;
+        use sync::Arc;
+        let re = Arc::new(compile(r"\d+").unwrap());
+        let set = Arc::new(compile_set([r"\d+", "x"]).unwrap());
+        let (re2, set2) = (re.clone(), set.clone());
+        let result = task::try(proc() {
+            assert!(re2.get().is_match("abc123"));
+            assert!(re2.get().matches("123"));
+            assert_eq!(set2.get().matches("x1"), ~[0, 1]);
+        });
+        assert!(result.is_ok());
+        assert!(!re.get().is_match("abc"));
+        assert!(!set.get().is_match("abc"));
+    }
+
+    /// Feed a string into a stream in chunks of the given size.
+    fn stream_chunks(re: &::Regex, s: &str, size: uint) -> ~[(u64, u64)] {
+        let bytes = s.as_bytes();
+        let mut stream = re.stream();
+        let mut found = ~[];
+        let mut i = 0;
+        while i < bytes.len() {
+            let end = ::std::cmp::min(i + size, bytes.len());
+            found.push_all(stream.feed(bytes.slice(i, end)).unwrap());
+            i = end;
+        }
+        found.push_all(stream.finish().unwrap());
+        found
+    }
+
+    #[test]
+    fn stream() {
+        let cases = [
+            (r"\d+", "1 22 333"),
+            ("a*", "aXa"),
+            ("a*", "XéX"),
+            ("", "ab"),
+            ("a+b|a", "aaab aa"),
+            (r"\bé+\b", "éé ééa é"),
+            (".*", "ab\ncd"),
+            ("z", "abc")
+        ];
+        for &(pattern, text) in cases.iter() {
+            let re = compile(pattern).unwrap();
+            let expected: ~[(u64, u64)] = re.find_iter(text)
+                .map(|(start, end)| (start as u64, end as u64)).collect();
+            for size in range(1u, 5) {
+                assert_eq!((pattern, size, stream_chunks(&re, text, size)),
+                           (pattern, size, expected.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn stream_invalid_utf8() {
+        let re = compile("a").unwrap();
+        let mut stream = re.stream();
+        assert!(stream.feed([0x61, 0xFF]).is_err());
+        let mut stream = re.stream();
+        assert!(stream.feed([0x61, 0xE2, 0x98]).is_ok());
+        assert!(stream.finish().is_err());
+    }
+
+    #[test]
+    fn find_reader() {
+        use std::io::MemReader;
+        let re = compile("b+").unwrap();
+        let mut reader = MemReader::new("abbcb".as_bytes().to_owned());
+        let mut found = ~[];
+        re.find_reader(&mut reader, |m| { found.push(m); true }).unwrap();
+        assert_eq!(found, ~[(1, 3), (4, 5)]);
+    }
+
+    #[test]
+    fn word_boundary() {
+        let re = compile(r"\bfoo\b").unwrap();
+        assert_eq!(re.search("foobar barfoo foo").unwrap().pos(0), Some((14, 17)));
+        assert!(re.search("foo_bar").is_none());
+        let re = compile(r"\Boo\B").unwrap();
+        assert_eq!(re.search("oops foo food").unwrap().pos(0), Some((10, 12)));
+    }
+
+    #[test]
+    fn unicode_word_boundary() {
+        let re = compile(r"(?u)\b\w+\b").unwrap();
+        assert_eq!(re.search("café").unwrap().pos(0), Some((0, 5)));
+        assert_eq!(re.search("  éa ").unwrap().pos(0), Some((2, 5)));
+        assert!(compile(r"(?u)é\b").unwrap().is_match("é!"));
+        assert!(!compile(r"(?u)é\b").unwrap().is_match("éa"));
+        assert!(compile(r"(?u)a\Bé").unwrap().is_match("aé"));
+        // Without the flag, only ASCII letters count
+        assert_eq!(compile(r"\b\w+\b").unwrap().search("café").unwrap().pos(0), Some((0, 3)));
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let re = compile("(?i)straße|[x-z]+").unwrap();
+        assert!(!re.matches("STRASSE"));
+        assert!(re.matches("STRAẞE"));
+        assert!(re.matches("xYz"));
+        assert!(!compile("(?i)[^a]").unwrap().matches("A"));
+        assert!(compile("a(?i:b)c").unwrap().matches("aBc"));
+        assert!(!compile("a(?i:b)c").unwrap().matches("aBC"));
+        assert!(!compile("(?i)a(?-i)b").unwrap().matches("AB"));
+        assert!(RegexBuilder::new("abc").case_insensitive(true).compile().unwrap().matches("ABC"));
+    }
+
+    #[test]
+    fn case_insensitive_named_classes() {
+        assert!(compile("(?i)[[:upper:]]").unwrap().matches("a"));
+        assert!(compile("(?i)[[:lower:]]").unwrap().matches("A"));
+        assert!(!compile("[[:upper:]]").unwrap().matches("a"));
+        assert!(compile(r"(?i)\p{Lu}").unwrap().matches("σ"));
+        assert!(compile(r"(?i)[\p{Ll}]").unwrap().matches("Σ"));
+        // Negated classes are folded before they're negated
+        assert!(!compile("(?i)[[:^upper:]]").unwrap().matches("a"));
+        assert!(compile("(?i)[[:^upper:]]").unwrap().matches("1"));
+        assert!(!compile(r"(?i)\P{Lu}").unwrap().matches("σ"));
+    }
+
+    #[test]
+    fn multi_line() {
+        let re = compile(r"(?m)^\w+$").unwrap();
+        assert_eq!(re.search("one two\nthree\n").unwrap().group(0), "three");
+        assert!(compile(r"^\w+$").unwrap().search("one two\nthree\n").is_none());
+    }
+
+    #[test]
+    fn dot_all() {
+        assert!(!compile("a.b").unwrap().matches("a\nb"));
+        assert!(compile("(?s)a.b").unwrap().matches("a\nb"));
+        assert!(RegexBuilder::new("a.b").dot_all(true).compile().unwrap().matches("a\nb"));
+    }
+
+    #[test]
+    fn extended() {
+        let re = compile("(?x) a b+ # comment\n c\\ d").unwrap();
+        assert!(re.matches("abbc d"));
+        let re = RegexBuilder::new("a | b").extended(true).compile().unwrap();
+        assert!(re.matches("b"));
+    }
+
+    #[test]
+    fn named_groups() {
+        let re = compile(r"(?P<year>\d{4})-(\d{2})-(?P<day>\d{2})").unwrap();
+        let names: ~[Option<&str>] = re.capture_names().collect();
+        assert_eq!(names, ~[None, Some("year"), None, Some("day")]);
+
+        let caps = re.search("on 2014-02-21").expect("no match");
+        assert_eq!(caps.name("year"), "2014");
+        assert_eq!(caps.name("day"), "21");
+        assert_eq!(caps.name("month"), "");
+        assert_eq!(caps.group(2), "02");
+    }
+
+    #[test]
+    fn unicode_classes() {
+        let re = compile(r"\p{Greek}+").unwrap();
+        assert_eq!(re.search("abc αβγ").unwrap().pos(0), Some((4, 10)));
+        let re = compile(r"\pL\PL").unwrap();
+        assert_eq!(re.search("ab1").unwrap().group(0), "b1");
+        let re = compile(r"[\p{Lu}\d]+").unwrap();
+        assert_eq!(re.search("xÀB9y").unwrap().group(0), "ÀB9");
+        assert!(compile(r"\p{Uppercase_Letter}").unwrap().matches("Σ"));
+    }
+
+    #[test]
+    fn unicode_classes_negated() {
+        // These tables have ranges on both sides of the surrogates
+        for pattern in [r"\PC", r"[\PC]", r"\P{Unknown}"].iter() {
+            let re = compile(*pattern).unwrap();
+            assert!(re.matches("a"));
+            assert!(!re.matches("\uE000"));
+            assert!(!re.matches("\uD7FF"));
+        }
+        assert!(!compile(r"\PC").unwrap().matches("\x01"));
+    }
+
+    #[test]
+    fn unicode_perl_classes() {
+        assert!(!compile(r"\w").unwrap().matches("é"));
+        assert!(compile(r"(?u)\w\d\s").unwrap().matches("é٣\u3000"));
+        assert!(!compile(r"(?u)\W").unwrap().matches("é"));
+        assert!(compile(r"(?u)(?-u:\W)").unwrap().matches("é"));
+        assert!(RegexBuilder::new(r"[\w]+").unicode(true).compile().unwrap().matches("привет"));
+    }
+
+    #[test]
+    fn posix_classes() {
+        let re = compile("[[:alpha:]_][[:alnum:]_]*").unwrap();
+        assert_eq!(re.search("1abc_2!").unwrap().group(0), "abc_2");
+        let re = compile("[[:^digit:][:space:]]+").unwrap();
+        assert_eq!(re.search("12ab c3").unwrap().group(0), "ab c");
+        assert!(compile("[^[:xdigit:]]").unwrap().matches("g"));
+        assert!(!compile("[^[:xdigit:]]").unwrap().matches("F"));
+    }
+
+    #[test]
+    fn class_set_operations() {
+        let re = compile(r"[\p{L}&&[^a-z]]+").unwrap();
+        assert_eq!(re.search("abcÉTÉxyz").unwrap().group(0), "ÉTÉ");
+        let re = compile(r"[\w--\d]+").unwrap();
+        assert_eq!(re.search("12ab_3").unwrap().group(0), "ab_");
+        let re = compile(r"[a-z--aeiou&&a-m]+").unwrap();
+        assert_eq!(re.search("abcdefghijklmnop").unwrap().group(0), "bcd");
+        assert!(compile("[^a-z--x]").unwrap().matches("x"));
+        assert!(!compile("[a-f&&x-z]").unwrap().matches("a"));
+        assert!(compile("[a&b]").unwrap().matches("&"));
+    }
+
+    #[test]
+    fn class_set_operations_empty() {
+        // Nested operations that leave nothing behind match nothing
+        for pattern in [r"[[a&&b]]", r"[[a--a]]", r"[[\p{Greek}&&\p{Latin}]]"].iter() {
+            let re = compile(*pattern).unwrap();
+            assert!(re.search("abcαβγ").is_none());
+        }
+        let re = compile(r"[x[a&&b]]+").unwrap();
+        assert_eq!(re.search("abxxb").unwrap().group(0), "xx");
+        assert!(compile(r"[^[a&&b]]").unwrap().matches("a"));
+    }
+
+    #[test]
+    fn find_iter() {
+        let re = compile(r"\d+").unwrap();
+        let found: ~[(uint, uint)] = re.find_iter("1 22 333").collect();
+        assert_eq!(found, ~[(0, 1), (2, 4), (5, 8)]);
+    }
+
+    #[test]
+    fn find_iter_empty() {
+        let re = compile("a*").unwrap();
+        let found: ~[(uint, uint)] = re.find_iter("aXa").collect();
+        assert_eq!(found, ~[(0, 1), (2, 3)]);
+        let found: ~[(uint, uint)] = re.find_iter("XéX").collect();
+        assert_eq!(found, ~[(0, 0), (1, 1), (3, 3), (4, 4)]);
+        let found: ~[(uint, uint)] = re.find_iter("").collect();
+        assert_eq!(found, ~[(0, 0)]);
+    }
+
+    #[test]
+    fn captures_iter() {
+        let re = compile(r"(\w)=(\d)").unwrap();
+        let pairs: ~[(&str, &str)] = re.captures_iter("a=1, b=2; c=3")
+            .map(|caps| (caps.group(1), caps.group(2))).collect();
+        assert_eq!(pairs, ~[("a", "1"), ("b", "2"), ("c", "3")]);
+        let re = compile(r"\bx").unwrap();
+        let found: ~[(uint, uint)] = re.find_iter("xx x").collect();
+        assert_eq!(found, ~[(0, 1), (3, 4)]);
+    }
+
+    #[test]
+    fn find_iter_long_input() {
+        let text = "ab ".repeat(20000);
+        let re = compile(r"\w+").unwrap();
+        let found: ~[(uint, uint)] = re.find_iter(text.as_slice()).collect();
+        assert_eq!(found.len(), 20000);
+        assert_eq!(found[0], (0, 2));
+        assert_eq!(*found.last().unwrap(), (59997, 59999));
+        let re = compile(r"(a)(b)?").unwrap();
+        assert_eq!(re.captures_iter(text.as_slice()).count(), 20000);
+    }
+
+    #[test]
+    fn split() {
+        let re = compile(r"\s+").unwrap();
+        let words: ~[&str] = re.split("  one two\tthree ").collect();
+        assert_eq!(words, ~["", "one", "two", "three", ""]);
+        let words: ~[&str] = re.split("").collect();
+        assert_eq!(words, ~[""]);
+        let chars: ~[&str] = compile("").unwrap().split("ab").collect();
+        assert_eq!(chars, ~["", "a", "b", ""]);
+    }
+
+    #[test]
+    fn splitn() {
+        let re = compile(",").unwrap();
+        let fields: ~[&str] = re.splitn("a,b,c,d", 2).collect();
+        assert_eq!(fields, ~["a", "b,c,d"]);
+        let fields: ~[&str] = re.splitn("a,b", 5).collect();
+        assert_eq!(fields, ~["a", "b"]);
+        let fields: ~[&str] = re.splitn("a,b", 0).collect();
+        assert_eq!(fields, ~[]);
+    }
+
+    #[test]
+    fn replace() {
+        let re = compile(r"(\w+)=(?P<value>\d+)").unwrap();
+        assert_eq!(re.replace("a=1 b=2", "$1: ${value}"), ~"a: 1 b=2");
+        assert_eq!(re.replace_all("a=1 b=2", "${1}_ = $$$2"), ~"a_ = $1 b_ = $2");
+        assert_eq!(re.replace_all("a=1", "$3$x${nope}$"), ~"$x$");
+        assert_eq!(re.replace_all("nothing here", "$1"), ~"nothing here");
+    }
+
+    #[test]
+    fn replace_with() {
+        let re = compile(r"\d+").unwrap();
+        let doubled = re.replace_all_with("1 2 30", |caps| {
+            let n: uint = from_str(caps.group(0)).unwrap();
+            (2 * n).to_str()
+        });
+        assert_eq!(doubled, ~"2 4 60");
+        assert_eq!(re.replace_with("1 2", |_| ~"x"), ~"x 2");
+        assert_eq!(compile("").unwrap().replace_all("ab", "-"), ~"-a-b-");
+    }
+
+    #[test]
+    fn exec_non_greedy() {
+        assert_eq!(compile("a+?").unwrap().exec("aaa").unwrap().group(0), "a");
+        assert_eq!(compile("a+").unwrap().exec("aaa").unwrap().group(0), "aaa");
+    }
+
+    #[test]
+    fn dump_program() {
+        let re = compile(r"(?P<y>\d)|\b").unwrap();
+        let expected = [
+            "0: save 0 (group 0 start)",
+            "1: split 2, 6",
+            "2: save 2 (group 1 <y> start)",
+            "3: range '0'-'9'",
+            "4: save 3 (group 1 <y> end)",
+            "5: jump 7",
+            r"6: assert \b",
+            "7: save 1 (group 0 end)",
+            "8: match 0",
+            ""
+        ];
+        assert_eq!(re.dump_program(), expected.connect("\n"));
+        assert_eq!(format!("{}", re), re.dump_program());
+    }
+
+    #[test]
+    fn program_ends_in_match() {
+        let re = compile("a|b").unwrap();
+        match re.program.last() {
+            Some(&::vm::Match(0)) => (),
+            _ => fail!("expected the program to end with `Match(0)`")
+        }
+    }
+
+    #[test]
+    fn regex_set() {
+        let set = compile_set([r"\bfoo\b", "ba+r", "^x", "[0-9]$", "(?i)BAZ"]).unwrap();
+        assert_eq!(set.len(), 5);
+        assert_eq!(set.matches("foo baaar"), ~[0, 1]);
+        assert_eq!(set.matches("xbaz 7"), ~[2, 3, 4]);
+        assert_eq!(set.matches("food"), ~[]);
+        assert!(set.is_match("bar"));
+        assert!(!set.is_match("food"));
+        assert_eq!(compile_set([]).unwrap().matches("abc"), ~[]);
+        assert!(compile_set(["a", "("]).is_err());
+    }
+
+    #[test]
+    fn regex_set_simulated() {
+        // `\Z` can't go through the DFA
+        let set = compile_set([r"a\Z", "b", ""]).unwrap();
+        assert_eq!(set.matches("ba\n"), ~[0, 1, 2]);
+        assert_eq!(set.matches("a\n\n"), ~[2]);
+        assert!(set.is_match("c"));
+    }
+
+    #[test]
+    fn leftmost_longest() {
+        use vm::LeftmostLongest;
+        let longest = |pattern: &str| {
+            RegexBuilder::new(pattern).semantics(LeftmostLongest).compile().unwrap()
+        };
+        assert_eq!(longest("a|ab").search("abc").unwrap().group(0), "ab");
+        assert_eq!(longest("a+?").exec("aaa").unwrap().group(0), "aaa");
+        assert_eq!(longest("x*|b").search("bx").unwrap().group(0), "b");
+        // Leftmost still beats longest
+        assert_eq!(longest("bcd|ab").search("abcd").unwrap().group(0), "ab");
+        let found: ~[(uint, uint)] = longest("a|ab").find_iter("abab").collect();
+        assert_eq!(found, ~[(0, 2), (2, 4)]);
+    }
+
+    #[test]
+    fn earliest() {
+        use vm::Earliest;
+        let earliest = |pattern: &str| {
+            RegexBuilder::new(pattern).semantics(Earliest).compile().unwrap()
+        };
+        assert_eq!(earliest("a+").search("baaa").unwrap().pos(0), Some((1, 2)));
+        assert_eq!(earliest("abc|b").search("abc").unwrap().pos(0), Some((1, 2)));
+        assert!(earliest("z").search("abc").is_none());
+    }
 }
@@ -1,32 +1,96 @@
 //! Parser and syntax tree.
 
 use std::char;
+use std::cmp::max;
+use std::fmt;
+use std::mem::replace;
+use std::str;
 
-use charclass::CharClass;
+use charclass::{CharClass, range_to_str};
 use charclass::ascii;
+use charclass::unicode;
+use vm::{Assertion, BeginText, EndText, EndTextNewline, BeginLine, EndLine};
+use vm::{WordBoundary, NotWordBoundary, UnicodeWordBoundary, NotUnicodeWordBoundary};
 
 
 /// A regular expression AST.
 pub enum Expr {
     Empty,
     Range(char, char),
+    Class(CharClass),
     Concatenate(~[Expr]),
     Alternate(~[Expr]),
     Repeat(~Expr, u32, Option<u32>, Greedy),
-    Capture(~Expr)
+    Capture(~Expr, Option<~str>),
+    Assert(Assertion)
 }
 
 impl Expr {
     pub fn accepts_empty(&self) -> bool {
         match *self {
             Empty => true,
-            Range(..) => false,
+            Range(..) | Class(..) => false,
             Concatenate(ref inners) => inners.iter().all(|e| e.accepts_empty()),
             Alternate(ref inners) => inners.iter().any(|e| e.accepts_empty()),
             Repeat(ref inner, min, _, _) => min == 0 || inner.accepts_empty(),
-            Capture(ref inner) => inner.accepts_empty()
+            Capture(ref inner, _) => inner.accepts_empty(),
+            Assert(..) => true
         }
     }
+
+    /// Write the tree rooted at this node, one node per line, indented
+    /// by depth.
+    fn fmt_indented(&self, f: &mut fmt::Formatter, depth: uint) -> fmt::Result {
+        try!(write!(f.buf, "{}", "  ".repeat(depth)));
+        let children: &[Expr] = match *self {
+            Empty => return write!(f.buf, "empty"),
+            Range(lo, hi) => return write!(f.buf, "range {}", range_to_str(lo, hi)),
+            Class(ref cc) => return write!(f.buf, "class {}", *cc),
+            Concatenate(ref inners) => {
+                try!(write!(f.buf, "concatenate"));
+                inners.as_slice()
+            },
+            Alternate(ref inners) => {
+                try!(write!(f.buf, "alternate"));
+                inners.as_slice()
+            },
+            Repeat(ref inner, min, max, greedy) => {
+                try!(write!(f.buf, "repeat {}..", min));
+                match max {
+                    Some(max) => try!(write!(f.buf, "{}", max)),
+                    None => ()
+                }
+                if greedy == NonGreedy {
+                    try!(write!(f.buf, " non-greedy"));
+                }
+                try!(write!(f.buf, "\n"));
+                return inner.fmt_indented(f, 1 + depth);
+            },
+            Capture(ref inner, ref name) => {
+                try!(write!(f.buf, "capture"));
+                match *name {
+                    Some(ref name) => try!(write!(f.buf, " <{}>", *name)),
+                    None => ()
+                }
+                try!(write!(f.buf, "\n"));
+                return inner.fmt_indented(f, 1 + depth);
+            },
+            Assert(ref assertion) => return write!(f.buf, "assert {}", *assertion)
+        };
+
+        for child in children.iter() {
+            try!(write!(f.buf, "\n"));
+            try!(child.fmt_indented(f, 1 + depth));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Show for Expr {
+    /// Show the syntax tree, one node per line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
 }
 
 
@@ -48,16 +112,150 @@ pub enum Greedy {
 static REPEAT_MAX: u32 = 100000;
 
 
-/// Parse a regular expression into an AST.  Fails on invalid syntax.
-pub fn parse(input: &str) -> Expr {
-    let mut s = State::new(input);
-    let e = p_alternate(&mut s);
+///
+/// Flags that change how a pattern is interpreted.
+///
+/// Each flag can also be set from within the pattern, either for the
+/// rest of the enclosing group (`(?i)`) or for a single group
+/// (`(?i:...)`).  A flag can be cleared by putting it after a minus
+/// sign, e.g. `(?i-s)`.
+///
+#[deriving(Clone, Eq)]
+pub struct Flags {
+    /// Match letters regardless of case (`i`).
+    case_insensitive: bool,
+    /// Make `^` and `$` match at the start and end of every line,
+    /// rather than the whole input (`m`).
+    multi_line: bool,
+    /// Allow `.` to match a newline (`s`).
+    dot_all: bool,
+    /// Ignore whitespace, and treat everything from `#` to the end of
+    /// the line as a comment (`x`).
+    extended: bool,
+    /// Use the Unicode definitions of `\d`, `\s` and `\w`, rather than
+    /// the ASCII ones (`u`).
+    unicode: bool
+}
+
+impl Flags {
+    /// Create a set of flags with everything turned off.
+    pub fn new() -> Flags {
+        Flags {
+            case_insensitive: false,
+            multi_line: false,
+            dot_all: false,
+            extended: false,
+            unicode: false
+        }
+    }
+}
+
+
+///
+/// A syntax error in a regular expression.
+///
+/// When displayed, this prints the message followed by the pattern,
+/// with the offending construct underlined:
+///
+/// ~~~ {.notrust}
+/// bad repeat interval
+///     a{3,1}
+///      ^~~~~
+/// ~~~
+///
+#[deriving(Clone, Eq)]
+pub struct Error {
+    /// What went wrong.
+    kind: ErrorKind,
+    /// The byte offset into the pattern where the offending construct
+    /// starts.
+    pos: uint,
+    /// The byte offset just past the end of the offending construct.
+    end: uint,
+    /// A human-readable description of the error.
+    msg: ~str,
+    /// The pattern that failed to parse.
+    pattern: ~str
+}
+
+impl fmt::Show for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let indent = self.pattern.slice_to(self.pos).char_len();
+        let width = max(1, self.pattern.slice(self.pos, self.end).char_len());
+        try!(write!(f.buf, "{}\n", self.msg));
+        try!(write!(f.buf, "    {}\n", self.pattern));
+        write!(f.buf, "    {}^{}", " ".repeat(indent), "~".repeat(width - 1))
+    }
+}
+
+
+/// The kinds of syntax error.
+#[deriving(Clone, Eq)]
+pub enum ErrorKind {
+    /// A closing parenthesis without a matching opening one.
+    UnbalancedParen,
+    /// An opening parenthesis without a matching closing one.
+    MismatchedParen,
+    /// A repetition operator applied to another one, e.g. `a**`.
+    MultipleRepeat,
+    /// A repetition operator with nothing before it, e.g. `*a`.
+    NothingToRepeat,
+    /// An unbounded repetition of something that matches the empty
+    /// string, e.g. `()*`.
+    RepeatEmpty,
+    /// A malformed counted repetition, e.g. `a{1`.
+    InvalidRepeat,
+    /// A counted repetition whose minimum exceeds its maximum.
+    BadRepeatInterval,
+    /// A counted repetition larger than `REPEAT_MAX`.
+    RepeatTooLarge,
+    /// An unrecognized `(?` extension.
+    UnknownExtension,
+    /// An unrecognized flag, e.g. `(?iq)`.
+    UnknownFlag,
+    /// A group name that is empty or not an identifier, e.g. `(?P<1>)`.
+    InvalidGroupName,
+    /// Two groups with the same name.
+    DuplicateGroupName,
+    /// The pattern ended in the middle of a construct.
+    UnexpectedEnd,
+    /// An unrecognized or malformed escape sequence.
+    InvalidEscape,
+    /// An escape sequence for a code point that does not exist.
+    CharOutOfRange,
+    /// An escape sequence for a surrogate code point, e.g. `\uD800`.
+    /// These are reserved for UTF-16 and can never appear in a string.
+    SurrogateEscape,
+    /// A `\p` escape naming a class that does not exist.
+    UnknownUnicodeClass,
+    /// A malformed or unknown POSIX class, e.g. `[[:alfa:]]`.
+    UnknownPosixClass,
+    /// A character range whose ends are not single characters, or are
+    /// out of order, e.g. `[z-a]`.
+    BadCharRange,
+    /// A character class that matches nothing, e.g. `[]`.
+    EmptyCharClass
+}
+
+
+/// Parse a regular expression into an AST.
+pub fn parse(input: &str) -> Result<Expr, Error> {
+    parse_with_flags(input, Flags::new())
+}
+
+
+/// Parse a regular expression into an AST, with the given flags set
+/// initially.
+pub fn parse_with_flags(input: &str, flags: Flags) -> Result<Expr, Error> {
+    let mut s = State::new(input, flags);
+    let e = try!(p_alternate(&mut s));
     if s.has_input() {
         // p_alternate() only terminates on an empty string or an extra
         // paren.  Since the string isn't empty, we infer the latter.
-        fail!("unbalanced parenthesis")
+        let pos = s.pos();
+        Err(s.error_span(UnbalancedParen, pos, pos + 1, ~"unbalanced parenthesis"))
     } else {
-        e
+        Ok(e)
     }
 }
 
@@ -65,15 +263,21 @@ pub fn parse(input: &str) -> Expr {
 /// The parser state.  This tracks the position in the input string.
 struct State<'a> {
     input: &'a str,
-    prev: Option<&'a str>  // See `State::retreat`
+    prev: Option<&'a str>,  // See `State::retreat`
+    pattern: &'a str,
+    flags: Flags,
+    names: ~[~str]  // Group names seen so far
 }
 
 
 impl<'a> State<'a> {
-    fn new<'a>(input: &'a str) -> State<'a> {
+    fn new<'a>(input: &'a str, flags: Flags) -> State<'a> {
         State {
             input: input,
-            prev: None
+            prev: None,
+            pattern: input,
+            flags: flags,
+            names: ~[]
         }
     }
 
@@ -97,6 +301,28 @@ impl<'a> State<'a> {
     fn has_input(&self) -> bool {
         self.input.len() > 0
     }
+
+    /// Return the byte offset of the next character to be read.
+    fn pos(&self) -> uint {
+        self.pattern.len() - self.input.len()
+    }
+
+    /// Create an error covering everything from `start` up to the
+    /// current position.
+    fn error(&self, kind: ErrorKind, start: uint, msg: ~str) -> Error {
+        self.error_span(kind, start, self.pos(), msg)
+    }
+
+    /// Create an error covering the given span of the pattern.
+    fn error_span(&self, kind: ErrorKind, start: uint, end: uint, msg: ~str) -> Error {
+        Error {
+            kind: kind,
+            pos: start,
+            end: end,
+            msg: msg,
+            pattern: self.pattern.to_owned()
+        }
+    }
 }
 
 
@@ -106,11 +332,11 @@ impl<'a> State<'a> {
 /// An alternation consists of zero or more concatenations, separated by
 /// vertical bars `|`.
 ///
-fn p_alternate(s: &mut State) -> Expr {
+fn p_alternate(s: &mut State) -> Result<Expr, Error> {
     let mut items: ~[Expr] = ~[];
 
     loop {
-        items.push(p_concatenate(s));
+        items.push(try!(p_concatenate(s)));
         match s.advance() {
             Some(c) => {
                 match c {
@@ -123,32 +349,41 @@ fn p_alternate(s: &mut State) -> Expr {
         }
     }
 
-    wrap_in(Alternate, items)
+    Ok(wrap_in(Alternate, items))
 }
 
 
 /// Parse concatenation, e.g. `abc`.
-fn p_concatenate(s: &mut State) -> Expr {
+fn p_concatenate(s: &mut State) -> Result<Expr, Error> {
     let mut items: ~[Expr] = ~[];
     let mut had_repeat = false;
 
     loop {
         let mut is_repeat = false;
+        let start = s.pos();
         match s.advance() {
             Some(c) => match c {
+                _ if s.flags.extended && ascii::space.includes(c) => continue,
+                '#' if s.flags.extended => { p_line_comment(s); continue },
                 '|' | ')' => { s.retreat(); break },
-                '(' => push_ignore_empty(&mut items, p_group(s)),
-                '.' => items.push(Range('\0', char::MAX)),
-                '\\' => items.push(cc_to_expr(p_escape(s))),
-                '[' => items.push(cc_to_expr(p_charclass(s))),
+                '(' => push_ignore_empty(&mut items, try!(p_group(s))),
+                '.' => items.push(if s.flags.dot_all {
+                    Range('\0', char::MAX)
+                } else {
+                    cc_to_expr(CharClass::from_char('\n').negate())
+                }),
+                '^' => items.push(Assert(if s.flags.multi_line { BeginLine } else { BeginText })),
+                '$' => items.push(Assert(if s.flags.multi_line { EndLine } else { EndText })),
+                '\\' => items.push(try!(p_escape_expr(s))),
+                '[' => items.push(cc_to_expr(try!(p_charclass(s)))),
                 '?' => {
-                    let e = pop_expr(&mut items);
+                    let e = try!(pop_expr(s, &mut items, start));
                     items.push(match e {
                         Repeat(inner, min, max, greedy) => {
                             if had_repeat && greedy == Greedy {
                                 Repeat(inner, min, max, NonGreedy)
                             } else {
-                                fail!("multiple repeat")
+                                return Err(s.error(MultipleRepeat, start, ~"multiple repeat"))
                             }
                         },
                         _ => {
@@ -158,26 +393,37 @@ fn p_concatenate(s: &mut State) -> Expr {
                     });
                 },
                 '+' => {
-                    add_repeat(&mut items, 1, None);
+                    try!(add_repeat(s, &mut items, 1, None, start));
                     is_repeat = true;
                 },
                 '*' => {
-                    add_repeat(&mut items, 0, None);
+                    try!(add_repeat(s, &mut items, 0, None, start));
                     is_repeat = true;
                 },
                 '{' => {
-                    let (min, max) = p_repetition(s);
-                    add_repeat(&mut items, min, max);
+                    let (min, max) = try!(p_repetition(s));
+                    try!(add_repeat(s, &mut items, min, max, start));
                     is_repeat = true;
                 },
-                _ => items.push(Range(c, c))
+                _ => items.push(literal(s, c))
             },
             None => break
         };
         had_repeat = is_repeat;
     }
 
-    wrap_in(Concatenate, items)
+    Ok(wrap_in(Concatenate, items))
+}
+
+
+/// Create an expression matching a single character, taking the
+/// case-insensitive flag into account.
+fn literal(s: &State, c: char) -> Expr {
+    if s.flags.case_insensitive {
+        cc_to_expr(CharClass::from_char(c).case_fold())
+    } else {
+        Range(c, c)
+    }
 }
 
 
@@ -201,19 +447,24 @@ fn push_ignore_empty(items: &mut ~[Expr], e: Expr) {
 
 
 #[inline]
-fn pop_expr(items: &mut ~[Expr]) -> Expr {
-    items.pop().expect("nothing to repeat")
+fn pop_expr(s: &State, items: &mut ~[Expr], start: uint) -> Result<Expr, Error> {
+    match items.pop() {
+        Some(e) => Ok(e),
+        None => Err(s.error(NothingToRepeat, start, ~"nothing to repeat"))
+    }
 }
 
 
 #[inline]
-fn add_repeat(items: &mut ~[Expr], min: u32, max: Option<u32>) {
-    let e = pop_expr(items);
-    items.push(match e {
-        Repeat(..) => fail!("multiple repeat"),
-        _ if max.is_none() && e.accepts_empty() => fail!("cannot repeat the empty string"),
-        _ => Repeat(~e, min, max, Greedy)
-    })
+fn add_repeat(s: &State, items: &mut ~[Expr], min: u32, max: Option<u32>, start: uint)
+        -> Result<(), Error> {
+    let e = try!(pop_expr(s, items, start));
+    match e {
+        Repeat(..) => Err(s.error(MultipleRepeat, start, ~"multiple repeat")),
+        _ if max.is_none() && e.accepts_empty() =>
+            Err(s.error(RepeatEmpty, start, ~"cannot repeat the empty string")),
+        _ => { items.push(Repeat(~e, min, max, Greedy)); Ok(()) }
+    }
 }
 
 
@@ -228,30 +479,31 @@ fn add_repeat(items: &mut ~[Expr], min: u32, max: Option<u32>) {
 /// * `{M,N}` – from M to N inclusive;
 /// * `{,}` – zero or more (synonymous with `*`).
 ///
-fn p_repetition(s: &mut State) -> (u32, Option<u32>) {
-    let min = p_number(s);
+fn p_repetition(s: &mut State) -> Result<(u32, Option<u32>), Error> {
+    let start = s.pos() - 1;
+    let min = try!(p_number(s));
     match s.advance() {
         Some(',') => {
-            let max = p_number(s);
+            let max = try!(p_number(s));
             match s.advance() {
                 // {} or {M,} or {,N} or {M,N}
                 Some('}') => {
                     let min_ = min.unwrap_or(0);
                     if check_repeat(min_, max) {
-                        (min_, max)
+                        Ok((min_, max))
                     } else {
-                        fail!("bad repeat interval")
+                        Err(s.error(BadRepeatInterval, start, ~"bad repeat interval"))
                     }
                 },
-                _ => fail!("invalid repeat")
+                _ => Err(s.error(InvalidRepeat, start, ~"invalid repeat"))
             }
         },
         Some('}') => match min {
             // {N}
-            Some(n) => (n, Some(n)),
-            _ => fail!("invalid repeat")
+            Some(n) => Ok((n, Some(n))),
+            _ => Err(s.error(InvalidRepeat, start, ~"invalid repeat"))
         },
-        _ => fail!("invalid repeat")
+        _ => Err(s.error(InvalidRepeat, start, ~"invalid repeat"))
     }
 }
 
@@ -268,10 +520,11 @@ fn check_repeat(min: u32, max: Option<u32>) -> bool {
 ///
 /// Parse a non-negative integer, and return it as a `u32`.
 ///
-/// This returns `None` if no number could be parsed, but fails directly
-/// if the number is greater than `REPEAT_MAX`.
+/// This returns `None` if no number could be parsed, and an error if
+/// the number is greater than `REPEAT_MAX`.
 ///
-fn p_number(s: &mut State) -> Option<u32> {
+fn p_number(s: &mut State) -> Result<Option<u32>, Error> {
+    let start = s.pos();
     let mut acc = None;
     loop {
         match s.advance() {
@@ -283,7 +536,8 @@ fn p_number(s: &mut State) -> Option<u32> {
                         if acc_ <= REPEAT_MAX {
                             acc_
                         } else {
-                            fail!(format!("repeat must be <= {}", REPEAT_MAX))
+                            return Err(s.error(RepeatTooLarge, start,
+                                format!("repeat must be <= {}", REPEAT_MAX)))
                         }
                     },
                     None => digit
@@ -292,28 +546,114 @@ fn p_number(s: &mut State) -> Option<u32> {
             _ => { s.retreat(); break }
         }
     }
-    acc
+    Ok(acc)
 }
 
 
 /// Parse a group (e.g. `(hello)`), sans the opening parenthesis.
-fn p_group(s: &mut State) -> Expr {
+fn p_group(s: &mut State) -> Result<Expr, Error> {
+    let start = s.pos() - 1;
+
+    // Any flags set within the group are local to it
+    let outer_flags = s.flags;
+
     let result = match s.advance() {
         Some('?') => match s.advance() {
             Some(c) => match c {
-                ':' => p_alternate(s),
+                ':' => try!(p_alternate(s)),
                 '#' => p_comment(s),
-                _ => fail!(format!("unknown extension: ?{}", c))
+                'P' => {
+                    let name = try!(p_group_name(s, start));
+                    Capture(~try!(p_alternate(s)), Some(name))
+                },
+                '-' | 'i' | 'm' | 's' | 'x' | 'u' => {
+                    s.retreat();
+                    s.flags = try!(p_flags(s, start));
+                    match s.advance() {
+                        Some(':') => try!(p_alternate(s)),
+                        // (?i) applies to the rest of the enclosing
+                        // group, so leave the flags as they are
+                        _ => return Ok(Empty)
+                    }
+                },
+                _ => return Err(s.error(UnknownExtension, start,
+                    format!("unknown extension: ?{}", c)))
             },
-            None => fail!("unexpected end of pattern")
+            None => return Err(s.error(UnexpectedEnd, start, ~"unexpected end of pattern"))
         },
-        _ => { s.retreat(); Capture(~p_alternate(s)) }
+        _ => { s.retreat(); Capture(~try!(p_alternate(s)), None) }
     };
 
+    s.flags = outer_flags;
+
     // Match the closing paren
     match s.advance() {
-        Some(')') => result,
-        _ => fail!("mismatched parenthesis")
+        Some(')') => Ok(result),
+        _ => Err(s.error(MismatchedParen, start, ~"mismatched parenthesis"))
+    }
+}
+
+
+///
+/// Parse the name of a named group (e.g. `<year>`), sans the leading
+/// `(?P`.
+///
+/// Names must be made of ASCII letters, digits and underscores, and
+/// cannot start with a digit.
+///
+fn p_group_name(s: &mut State, start: uint) -> Result<~str, Error> {
+    match s.advance() {
+        Some('<') => (),
+        Some(c) => return Err(s.error(UnknownExtension, start,
+            format!("unknown extension: ?P{}", c))),
+        None => return Err(s.error(UnexpectedEnd, start, ~"unexpected end of pattern"))
+    }
+
+    let mut name = ~"";
+    loop {
+        match s.advance() {
+            Some('>') => break,
+            Some(c) if ascii::word.includes(c) && !(name.is_empty() && ascii::digit.includes(c)) =>
+                name.push_char(c),
+            Some(_) => return Err(s.error(InvalidGroupName, start, ~"invalid group name")),
+            None => return Err(s.error(UnexpectedEnd, start, ~"unexpected end of pattern"))
+        }
+    }
+
+    if name.is_empty() {
+        Err(s.error(InvalidGroupName, start, ~"invalid group name"))
+    } else if s.names.contains(&name) {
+        Err(s.error(DuplicateGroupName, start, format!("duplicate group name: {}", name)))
+    } else {
+        s.names.push(name.clone());
+        Ok(name)
+    }
+}
+
+
+///
+/// Parse a list of flags to set or clear (e.g. `i-sx`), and return the
+/// current flags with those changes applied.
+///
+/// This stops before the `:` or `)` that ends the list.
+///
+fn p_flags(s: &mut State, start: uint) -> Result<Flags, Error> {
+    let mut flags = s.flags;
+    let mut enable = true;
+    loop {
+        match s.advance() {
+            Some(c) => match c {
+                '-' if enable => enable = false,
+                'i' => flags.case_insensitive = enable,
+                'm' => flags.multi_line = enable,
+                's' => flags.dot_all = enable,
+                'x' => flags.extended = enable,
+                'u' => flags.unicode = enable,
+                ':' | ')' => { s.retreat(); return Ok(flags) },
+                _ => return Err(s.error(UnknownFlag, start, format!("unknown flag: {}", c)))
+            },
+            None => return Err(s.error(UnexpectedEnd, start, ~"unexpected end of pattern"))
+        }
     }
 }
 
@@ -331,68 +671,207 @@ fn p_comment(s: &mut State) -> Expr {
 }
 
 
+/// Consume all input up to and including the next newline.  This is
+/// used for comments in extended mode.
+fn p_line_comment(s: &mut State) {
+    loop {
+        match s.advance() {
+            Some('\n') | None => break,
+            Some(_) => continue
+        }
+    }
+}
+
+
+///
+/// Parse an escape sequence outside a character class, sans the leading
+/// backslash.
+///
+/// On top of what `p_escape` accepts, this allows the zero-width
+/// assertions `\A`, `\z`, `\Z`, `\b` and `\B`.
+///
+fn p_escape_expr(s: &mut State) -> Result<Expr, Error> {
+    match s.advance() {
+        Some('A') => Ok(Assert(BeginText)),
+        Some('z') => Ok(Assert(EndText)),
+        Some('Z') => Ok(Assert(EndTextNewline)),
+        Some('b') => Ok(Assert(if s.flags.unicode {
+            UnicodeWordBoundary
+        } else {
+            WordBoundary
+        })),
+        Some('B') => Ok(Assert(if s.flags.unicode {
+            NotUnicodeWordBoundary
+        } else {
+            NotWordBoundary
+        })),
+        _ => {
+            s.retreat();
+            let cc = try!(p_escape(s));
+            if s.flags.case_insensitive && cc.to_char().is_some() {
+                Ok(cc_to_expr(cc.case_fold()))
+            } else {
+                Ok(cc_to_expr(cc))
+            }
+        }
+    }
+}
+
+
 /// Parse an escape sequence (e.g. `\d`), sans the leading backslash.
-fn p_escape(s: &mut State) -> CharClass {
+fn p_escape(s: &mut State) -> Result<CharClass, Error> {
+    let start = s.pos() - 1;
     match s.advance() {
         Some(c) => match c {
-            'n' => CharClass::from_char('\n'),
-            'r' => CharClass::from_char('\r'),
-            't' => CharClass::from_char('\t'),
+            'n' => Ok(CharClass::from_char('\n')),
+            'r' => Ok(CharClass::from_char('\r')),
+            't' => Ok(CharClass::from_char('\t')),
+
+            'd' | 's' | 'w' => Ok(perl_class(s, c)),
+            'D' => Ok(perl_class(s, 'd').negate()),
+            'S' => Ok(perl_class(s, 's').negate()),
+            'W' => Ok(perl_class(s, 'w').negate()),
 
-            'd' => ascii::digit.clone(),
-            's' => ascii::space.clone(),
-            'w' => ascii::word.clone(),
+            'p' => p_unicode_class(s, start, false),
+            'P' => p_unicode_class(s, start, true),
 
-            'D' => ascii::digit.negate(),
-            'S' => ascii::space.negate(),
-            'W' => ascii::word.negate(),
+            'x' => p_hex_escape(s, start, 2),
+            'u' => p_hex_escape(s, start, 4),
+            'U' => p_hex_escape(s, start, 8),
 
-            'x' => p_hex_escape(s, 2),
-            'u' => p_hex_escape(s, 4),
-            'U' => p_hex_escape(s, 8),
+            // Spaces need escaping in extended mode
+            ' ' => Ok(CharClass::from_char(' ')),
 
-            _ if ascii::punct.includes(c) => CharClass::from_char(c),
+            _ if ascii::punct.includes(c) => Ok(CharClass::from_char(c)),
 
-            _ => fail!("invalid escape")
+            _ => Err(s.error(InvalidEscape, start, ~"invalid escape"))
         },
-        None => fail!("invalid escape")
+        None => Err(s.error(InvalidEscape, start, ~"invalid escape"))
     }
 }
 
 
-fn p_hex_escape(s: &mut State, n_digits: uint) -> CharClass {
+/// Return the class for `\d`, `\s` or `\w`, taking the Unicode flag
+/// into account.
+fn perl_class(s: &State, c: char) -> CharClass {
+    match (c, s.flags.unicode) {
+        ('d', false) => ascii::digit.clone(),
+        ('s', false) => ascii::space.clone(),
+        ('w', false) => ascii::word.clone(),
+        ('d', true) => unicode::Nd.clone(),
+        ('s', true) => unicode::space.clone(),
+        ('w', true) => unicode::word.clone(),
+        _ => unreachable!()
+    }
+}
+
+
+fn p_hex_escape(s: &mut State, start: uint, n_digits: uint) -> Result<CharClass, Error> {
     let mut acc = 0u32;
     for _ in range(0, n_digits) {
-        acc = 16 * acc + match s.advance() {
-            Some(c) => c.to_digit(16).expect("invalid escape") as u32,
-            _ => fail!("invalid escape")
+        acc = 16 * acc + match s.advance().and_then(|c| c.to_digit(16)) {
+            Some(d) => d as u32,
+            None => return Err(s.error(InvalidEscape, start, ~"invalid escape"))
         };
     }
-    let c = char::from_u32(acc).expect("character out of range");
-    CharClass::from_char(c)
+    match char::from_u32(acc) {
+        Some(c) => Ok(CharClass::from_char(c)),
+        None if 0xD800 <= acc && acc <= 0xDFFF =>
+            Err(s.error(SurrogateEscape, start,
+                format!("surrogate code point U+{:X} is not allowed", acc))),
+        None => Err(s.error(CharOutOfRange, start, ~"character out of range"))
+    }
+}
+
+
+///
+/// Parse a Unicode general category or script (e.g. `\pL` or
+/// `\p{Greek}`), sans the leading `\p` or `\P`.
+///
+/// One-letter names can be written without braces.
+///
+fn p_unicode_class(s: &mut State, start: uint, negate: bool) -> Result<CharClass, Error> {
+    let name = match s.advance() {
+        Some('{') => {
+            let mut name = ~"";
+            loop {
+                match s.advance() {
+                    Some('}') => break,
+                    Some(c) => name.push_char(c),
+                    None => return Err(s.error(UnexpectedEnd, start, ~"unexpected end of pattern"))
+                }
+            }
+            name
+        },
+        Some(c) => str::from_char(c),
+        None => return Err(s.error(InvalidEscape, start, ~"invalid escape"))
+    };
+
+    match unicode::general_category(name.as_slice()).or_else(|| unicode::script(name.as_slice())) {
+        Some(cc) => Ok(named_class(s, cc, negate)),
+        None => Err(s.error(UnknownUnicodeClass, start,
+            format!("unknown Unicode class: {}", name)))
+    }
 }
 
 
+///
 /// Parse a character class (e.g. `[a-z]`), sans the opening bracket.
-fn p_charclass(s: &mut State) -> CharClass {
+///
+/// A class can be split into operands with `&&` (intersection) and `--`
+/// (difference), e.g. `[\w--\d]`.  These are applied from left to
+/// right, and a leading `^` negates the result as a whole.
+///
+fn p_charclass(s: &mut State) -> Result<CharClass, Error> {
+    let start = s.pos() - 1;
     let mut classes: ~[CharClass] = ~[];
 
+    // Everything left of the last `&&` or `--`, and which one it was
+    let mut lhs: Option<(CharClass, SetOp)> = None;
+
     let negate = match s.advance() {
         Some('^') => true,
         _ => { s.retreat(); false }
     };
 
+    // The start of the most recent token, for error reporting
+    let mut token_start = s.pos();
+
     loop {
+        let pos = s.pos();
         match s.advance() {
             Some(c) => match c {
                 ']' => break,
-                '-' => match p_charclass_token(s) {
+                '&' => {
+                    if s.advance() == Some('&') {
+                        let rhs = try!(charclass_operand(s, replace(&mut classes, ~[]), start));
+                        lhs = Some((apply_set_op(lhs, rhs), Intersect));
+                    } else {
+                        s.retreat();
+                        token_start = pos;
+                        classes.push(CharClass::from_char('&'));
+                    }
+                },
+                '-' if s.advance() == Some('-') => {
+                    let rhs = try!(charclass_operand(s, replace(&mut classes, ~[]), start));
+                    lhs = Some((apply_set_op(lhs, rhs), Subtract));
+                },
+                '-' => match { s.retreat(); try!(p_charclass_token(s)) } {
                     Some(cc_hi) => match classes.pop() {
                         Some(cc_lo) => {
                             // [a-z]
-                            let lo = cc_lo.to_char().expect("bad character range");
-                            let hi = cc_hi.to_char().expect("bad character range");
-                            classes.push(CharClass::from_range(lo, hi));
+                            match (cc_lo.to_char(), cc_hi.to_char()) {
+                                (Some(lo), Some(hi)) if lo <= hi => {
+                                    let cc = CharClass::from_range(lo, hi);
+                                    classes.push(if s.flags.case_insensitive {
+                                        cc.case_fold()
+                                    } else {
+                                        cc
+                                    });
+                                },
+                                _ => return Err(s.error(BadCharRange, token_start,
+                                    ~"bad character range"))
+                            }
                         },
                         None => {
                             // [-z]
@@ -404,60 +883,229 @@ fn p_charclass(s: &mut State) -> CharClass {
                 },
                 _ => {
                     s.retreat();
-                    classes.push(p_charclass_token(s).expect("invalid char class"));
+                    token_start = pos;
+                    match try!(p_charclass_token(s)) {
+                        Some(cc) => classes.push(cc),
+                        None => unreachable!()
+                    }
                 }
             },
-            None => fail!("unexpected end of char class")
+            None => return Err(s.error(UnexpectedEnd, start, ~"unexpected end of char class"))
         }
     }
 
-    let cc = CharClass::combine(classes);
+    let rhs = try!(charclass_operand(s, classes, start));
+    let cc = apply_set_op(lhs, rhs);
     if negate {
-        cc.negate()
+        Ok(cc.negate())
     } else {
-        cc
+        Ok(cc)
+    }
+}
+
+
+/// A set operation within a character class.
+enum SetOp {
+    Intersect,
+    Subtract
+}
+
+
+/// Combine the left-hand side of a set operation, if there is one, with
+/// its right-hand side.
+fn apply_set_op(lhs: Option<(CharClass, SetOp)>, rhs: CharClass) -> CharClass {
+    match lhs {
+        Some((cc, Intersect)) => cc.intersect(&rhs),
+        Some((cc, Subtract)) => cc.difference(&rhs),
+        None => rhs
     }
 }
 
 
-fn p_charclass_token(s: &mut State) -> Option<CharClass> {
+/// Combine the tokens between set operators into a single class.
+fn charclass_operand(s: &State, mut classes: ~[CharClass], start: uint)
+        -> Result<CharClass, Error> {
+    if classes.is_empty() {
+        return Err(s.error(EmptyCharClass, start, ~"char class cannot be empty"));
+    }
+
+    // Ranges and named classes have been folded already, but single
+    // characters are left until now in case they turn out to be the end
+    // of a range
+    if s.flags.case_insensitive {
+        classes = classes.move_iter().map(|cc| {
+            if cc.to_char().is_some() { cc.case_fold() } else { cc }
+        }).collect();
+    }
+
+    Ok(CharClass::combine(classes))
+}
+
+
+fn p_charclass_token(s: &mut State) -> Result<Option<CharClass>, Error> {
     match s.advance() {
         Some(c) => match c {
-            ']' => { s.retreat(); None },
-            '[' => Some(p_charclass(s)),
-            '\\' => Some(p_escape(s)),
-            _ => Some(CharClass::from_char(c))
+            ']' => { s.retreat(); Ok(None) },
+            '[' => match s.advance() {
+                Some(':') => Ok(Some(try!(p_posix_class(s)))),
+                _ => { s.retreat(); Ok(Some(try!(p_charclass(s)))) }
+            },
+            '\\' => Ok(Some(try!(p_escape(s)))),
+            _ => Ok(Some(CharClass::from_char(c)))
         },
-        None => None
+        None => Ok(None)
+    }
+}
+
+
+/// Parse a POSIX class (e.g. `[:alpha:]` or `[:^digit:]`), sans the
+/// leading `[:`.
+fn p_posix_class(s: &mut State) -> Result<CharClass, Error> {
+    let start = s.pos() - 2;
+
+    let negate = match s.advance() {
+        Some('^') => true,
+        _ => { s.retreat(); false }
+    };
+
+    let mut name = ~"";
+    loop {
+        match s.advance() {
+            Some(':') => break,
+            Some(']') => return Err(s.error(UnknownPosixClass, start, ~"invalid POSIX class")),
+            Some(c) => name.push_char(c),
+            None => return Err(s.error(UnexpectedEnd, start, ~"unexpected end of char class"))
+        }
+    }
+
+    match (s.advance(), ascii::posix_class(name.as_slice())) {
+        (Some(']'), Some(cc)) => Ok(named_class(s, cc, negate)),
+        _ => Err(s.error(UnknownPosixClass, start, ~"invalid POSIX class"))
+    }
+}
+
+
+/// Apply the `i` flag and any negation to a POSIX or Unicode class.
+/// The class has to be folded first: folding `[:^upper:]` after
+/// negating it would bring the uppercase letters back in.
+fn named_class(s: &State, cc: CharClass, negate: bool) -> CharClass {
+    let cc = if s.flags.case_insensitive { cc.case_fold() } else { cc };
+    if negate {
+        cc.negate()
+    } else {
+        cc
     }
 }
 
 
 /// Reify a character class as an `Expr`.
 fn cc_to_expr(cc: CharClass) -> Expr {
-    Alternate(cc.ranges().iter().map(|&(lo, hi)| Range(lo, hi)).collect())
+    let single = match cc.ranges() {
+        [(lo, hi)] => Some((lo, hi)),
+        _ => None
+    };
+    match single {
+        Some((lo, hi)) => Range(lo, hi),
+        None => Class(cc)
+    }
 }
 
 
 #[cfg(test)]
 mod test {
-    use super::parse;
+    use super::{parse, Error, ErrorKind};
+    use super::{MultipleRepeat, RepeatEmpty, UnbalancedParen, MismatchedParen,
+                BadRepeatInterval, BadCharRange, EmptyCharClass, InvalidEscape,
+                InvalidGroupName, DuplicateGroupName, UnknownUnicodeClass,
+                UnknownPosixClass, SurrogateEscape, CharOutOfRange};
+
+    fn error(input: &str) -> Error {
+        match parse(input) {
+            Ok(_) => fail!("expected {} to be rejected", input),
+            Err(e) => e
+        }
+    }
+
+    fn error_kind(input: &str) -> ErrorKind {
+        error(input).kind
+    }
 
     #[test]
-    #[should_fail]
     fn issue_3() {
-        let _ = parse("(?:a+)?");  // error: multiple repeat
+        assert_eq!(error_kind("(?:a+)?"), MultipleRepeat);
     }
 
     #[test]
-    #[should_fail]
     fn issue_4_a() {
-        let _ = parse("()+");  // error: cannot repeat the empty string
+        assert_eq!(error_kind("()+"), RepeatEmpty);
     }
 
     #[test]
-    #[should_fail]
     fn issue_4_b() {
-        let _ = parse("(?:a|b|)*");  // error: cannot repeat the empty string
+        assert_eq!(error_kind("(?:a|b|)*"), RepeatEmpty);
+    }
+
+    #[test]
+    fn error_positions() {
+        let e = error("ab)c");
+        assert_eq!(e.kind, UnbalancedParen);
+        assert_eq!(e.pos, 2);
+        let e = error(r"a\q");
+        assert_eq!(e.kind, InvalidEscape);
+        assert_eq!(e.pos, 1);
+    }
+
+    #[test]
+    fn error_kinds() {
+        assert_eq!(error_kind("(ab"), MismatchedParen);
+        assert_eq!(error_kind("a{3,1}"), BadRepeatInterval);
+        assert_eq!(error_kind("[z-a]"), BadCharRange);
+        assert_eq!(error_kind("[]"), EmptyCharClass);
+        assert_eq!(error_kind("[a&&]"), EmptyCharClass);
+        assert_eq!(error_kind("[--a]"), EmptyCharClass);
+        assert_eq!(error_kind("(?P<1st>a)"), InvalidGroupName);
+        assert_eq!(error_kind("(?P<>a)"), InvalidGroupName);
+        assert_eq!(error_kind("(?P<a>x)(?P<a>y)"), DuplicateGroupName);
+        assert_eq!(error_kind(r"\p{Klingon}"), UnknownUnicodeClass);
+        assert_eq!(error_kind("[[:alfa:]]"), UnknownPosixClass);
+        assert_eq!(error_kind("[[:alpha]]"), UnknownPosixClass);
+        assert_eq!(error_kind(r"\uD800"), SurrogateEscape);
+        assert_eq!(error_kind(r"[a-\uDFFF]"), SurrogateEscape);
+        assert_eq!(error_kind(r"\U00110000"), CharOutOfRange);
+    }
+
+    #[test]
+    fn error_spans() {
+        let e = error("x(?<y)");
+        assert_eq!((e.pos, e.end), (1, 4));
+        let e = error("ab{3,1}c");
+        assert_eq!((e.pos, e.end), (2, 7));
+        let e = error(r"a\uDC00b");
+        assert_eq!((e.pos, e.end), (1, 7));
+    }
+
+    #[test]
+    fn error_display() {
+        let e = error("a{3,1}");
+        assert_eq!(format!("{}", e), ~"bad repeat interval\n    a{3,1}\n     ^~~~~");
+        let e = error("é[");
+        assert_eq!(format!("{}", e), ~"unexpected end of char class\n    é[\n     ^");
+    }
+
+    #[test]
+    fn expr_display() {
+        let e = parse("(?P<x>a|b+?)c{2,3}$").unwrap();
+        let expected = [
+            "concatenate",
+            "  capture <x>",
+            "    alternate",
+            "      range 'a'",
+            "      repeat 1.. non-greedy",
+            "        range 'b'",
+            "  repeat 2..3",
+            "    range 'c'",
+            r"  assert \z"
+        ];
+        assert_eq!(format!("{}", e), expected.connect("\n"));
     }
 }
@@ -0,0 +1,223 @@
+//!
+//! Searching input that arrives in pieces.
+//!
+//! The VM already consumes one character at a time, so the only tricky
+//! part is restarting it after a match.  The VM keeps running past the
+//! end of a match while a better one might come along, so anything fed
+//! in after that point has to be fed in again.  Before anything matches,
+//! only the last couple of characters are kept around; after that, the
+//! characters since the match ended are kept until the VM gives up.
+//!
+
+use std::cmp::min;
+use std::io;
+use std::io::IoResult;
+use std::mem::replace;
+use std::str;
+
+use super::Regex;
+use vm::VM;
+
+
+///
+/// A search over a stream of UTF-8 text.  Use `Regex::stream` to get one
+/// of these.
+///
+/// Matches are found in the same way as `Regex::find_iter`, and are
+/// reported as byte offsets from the start of the stream.
+///
+pub struct Stream<'r> {
+    priv regex: &'r Regex,
+    priv vm: VM<'r>,
+    /// Characters that may need to be fed into the next VM
+    priv buffer: ~[(u64, char)],
+    /// The start of a code point that was split between chunks
+    priv partial: ~[u8],
+    /// The offset of the next byte in the stream
+    priv offset: u64,
+    priv last_end: Option<u64>
+}
+
+impl<'r> Stream<'r> {
+    /// Start a search at the beginning of a stream.
+    pub fn new(regex: &'r Regex) -> Stream<'r> {
+        Stream {
+            regex: regex,
+            vm: VM::unanchored(regex.program).with_semantics(regex.semantics),
+            buffer: ~[],
+            partial: ~[],
+            offset: 0,
+            last_end: None
+        }
+    }
+
+    ///
+    /// Feed the next chunk of the stream, and return the bounds of any
+    /// matches that are now known to be complete.
+    ///
+    /// A chunk can end partway through a code point; the rest of it is
+    /// expected at the start of the next one.  Fails with `InvalidInput`
+    /// if the chunk is not valid UTF-8.
+    ///
+    pub fn feed(&mut self, chunk: &[u8]) -> IoResult<~[(u64, u64)]> {
+        let mut chars = ~[];
+        let mut chunk = chunk;
+
+        // Finish off the code point from the last chunk
+        if !self.partial.is_empty() {
+            let width = str::utf8_char_width(self.partial[0]);
+            let needed = width - self.partial.len();
+            if chunk.len() < needed {
+                self.partial.push_all(chunk);
+                return Ok(~[]);
+            }
+            self.partial.push_all(chunk.slice_to(needed));
+            chunk = chunk.slice_from(needed);
+            let partial = replace(&mut self.partial, ~[]);
+            try!(self.decode(partial, &mut chars));
+        }
+
+        // Hold back a code point that is cut off at the end
+        let cut = incomplete_suffix(chunk);
+        try!(self.decode(chunk.slice_to(cut), &mut chars));
+        self.partial.push_all(chunk.slice_from(cut));
+
+        let mut found = ~[];
+        self.run(chars, &mut found);
+        Ok(found)
+    }
+
+    /// Signal the end of the stream, and return the bounds of the
+    /// remaining matches.  Fails with `InvalidInput` if the stream ends
+    /// partway through a code point.
+    pub fn finish(mut self) -> IoResult<~[(u64, u64)]> {
+        if !self.partial.is_empty() {
+            return Err(invalid_utf8());
+        }
+
+        let mut found = ~[];
+        loop {
+            self.vm.finish();
+            if !self.vm.is_match() {
+                break
+            }
+            match self.restart(&mut found) {
+                Some(replay) => self.run(replay, &mut found),
+                None => break
+            }
+        }
+        Ok(found)
+    }
+
+    /// Decode some bytes, and add the resulting characters to the list
+    /// along with their offsets.
+    fn decode(&mut self, bytes: &[u8], chars: &mut ~[(u64, char)]) -> IoResult<()> {
+        let s = match str::from_utf8(bytes) {
+            Some(s) => s,
+            None => return Err(invalid_utf8())
+        };
+        for c in s.chars() {
+            chars.push((self.offset, c));
+            self.offset += c.len_utf8_bytes() as u64;
+        }
+        Ok(())
+    }
+
+    /// Feed characters into the VM, restarting it after every match.
+    fn run(&mut self, chars: ~[(u64, char)], found: &mut ~[(u64, u64)]) {
+        // Characters are popped off the end, so keep them in reverse
+        let mut todo = chars;
+        todo.reverse();
+        loop {
+            let (offset, c) = match todo.pop() {
+                Some(next) => next,
+                None => break
+            };
+            self.buffer.push((offset, c));
+            self.vm.feed(c);
+
+            if self.vm.is_done() {
+                match self.restart(found) {
+                    Some(replay) => for &next in replay.iter().rev() {
+                        todo.push(next);
+                    },
+                    None => ()
+                }
+            } else if !self.vm.is_match() && self.buffer.len() > 2 {
+                // A match could only end at the last character, and
+                // the one before that is needed as context
+                self.buffer.remove(0);
+            }
+        }
+    }
+
+    ///
+    /// Record the match the VM has found, and start a new VM at the end
+    /// of it.  Return the characters that have to be fed in again, or
+    /// `None` if there's nowhere left to search.
+    ///
+    /// As in `Regex::find_iter`, an empty match directly after the
+    /// previous one is skipped.
+    ///
+    fn restart(&mut self, found: &mut ~[(u64, u64)]) -> Option<~[(u64, char)]> {
+        let (start, end) = match self.vm.registers() {
+            Some(registers) => (registers[0].unwrap(), registers[1].unwrap()),
+            None => unreachable!()
+        };
+
+        let skip = start == end && Some(end) == self.last_end;
+        if !skip {
+            found.push((start, end));
+            self.last_end = Some(end);
+        }
+
+        let mut prev = None;
+        let mut replay = ~[];
+        for &(offset, c) in replace(&mut self.buffer, ~[]).iter() {
+            if offset < end {
+                prev = Some(c);
+            } else {
+                replay.push((offset, c));
+            }
+        }
+
+        let mut next = end;
+        if skip {
+            // Move forward a character and try again
+            let (offset, c) = match replay.head() {
+                Some(&first) => first,
+                None => return None
+            };
+            next = offset + c.len_utf8_bytes() as u64;
+            prev = Some(c);
+            replay = replay.slice_from(1).to_owned();
+        }
+
+        self.vm.restart(next, prev);
+        Some(replay)
+    }
+}
+
+
+/// Return the length of a chunk, minus any code point that is cut off at
+/// the end of it.
+fn incomplete_suffix(chunk: &[u8]) -> uint {
+    let len = chunk.len();
+    for i in range(1, 1 + min(len, 4)) {
+        let b = chunk[len - i];
+        if b & 0xC0 != 0x80 {
+            // Found the first byte of the last code point
+            return if str::utf8_char_width(b) > i { len - i } else { len };
+        }
+    }
+    len
+}
+
+
+fn invalid_utf8() -> io::IoError {
+    io::IoError {
+        kind: io::InvalidInput,
+        desc: "invalid UTF-8 in stream",
+        detail: None
+    }
+}
@@ -1,9 +1,14 @@
 //! Regular expression virtual machine.
 
 use std;
-use std::mem::swap;
+use std::fmt;
+use std::mem::replace;
 use collections::TrieSet;
 
+use charclass::{CharClass, range_to_str};
+use charclass::ascii;
+use charclass::unicode;
+
 
 /// A single instruction in the program.
 pub enum Inst {
@@ -14,8 +19,146 @@ pub enum Inst {
     /// Match any code point in the range, inclusive.
     Range(char, char),
 
+    /// Match any code point in the class.  This is equivalent to a
+    /// `Jump` to a `Range` for each of its ranges, but only takes up a
+    /// single thread.
+    Class(CharClass),
+
     /// Save the current position in the specified register.
-    Save(uint)
+    Save(uint),
+
+    /// Match the empty string, but only if the assertion holds at the
+    /// current position.
+    Assert(Assertion),
+
+    /// Stop with a match for the pattern with the given id.  A single
+    /// regex always uses id 0; a `RegexSet` numbers its patterns in
+    /// order.
+    Match(uint)
+}
+
+
+impl fmt::Show for Inst {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Jump(ref exits) => match exits.as_slice() {
+                [] => write!(f.buf, "fail"),
+                [exit] => write!(f.buf, "jump {}", exit),
+                _ => {
+                    let exits: ~[~str] = exits.iter().map(|exit| exit.to_str()).collect();
+                    write!(f.buf, "split {}", exits.connect(", "))
+                }
+            },
+            Range(lo, hi) if lo == hi => write!(f.buf, "char {}", range_to_str(lo, hi)),
+            Range(lo, hi) => write!(f.buf, "range {}", range_to_str(lo, hi)),
+            Class(ref cc) => write!(f.buf, "class {}", *cc),
+            Save(reg) => write!(f.buf, "save {}", reg),
+            Assert(ref assertion) => write!(f.buf, "assert {}", *assertion),
+            Match(id) => write!(f.buf, "match {}", id)
+        }
+    }
+}
+
+
+/// Which match to report when there are several candidates.
+#[deriving(Clone, Eq)]
+pub enum MatchSemantics {
+    /// Prefer the match that starts first, then the one that comes first
+    /// in priority order, as in Perl.  This is the default.
+    LeftmostFirst,
+    /// Prefer the match that starts first, then the longest one, as in
+    /// POSIX.
+    LeftmostLongest,
+    /// Stop at the first match to end, wherever it starts.  This is
+    /// the fastest way to find out if there is a match at all.
+    Earliest
+}
+
+
+/// A zero-width assertion about the current position.
+#[deriving(Clone, Eq)]
+pub enum Assertion {
+    /// The start of the input (`\A`).
+    BeginText,
+    /// The end of the input (`\z`).
+    EndText,
+    /// The end of the input, or just before a newline that ends the
+    /// input (`\Z`).
+    EndTextNewline,
+    /// The start of the input, or just after a newline.
+    BeginLine,
+    /// The end of the input, or just before a newline.
+    EndLine,
+    /// Between a word character and a non-word character (`\b`).
+    WordBoundary,
+    /// Not at a word boundary (`\B`).
+    NotWordBoundary,
+    /// Like `WordBoundary`, but using the Unicode definition of a word
+    /// character (`(?u)\b`).
+    UnicodeWordBoundary,
+    /// Not at a Unicode word boundary (`(?u)\B`).
+    NotUnicodeWordBoundary
+}
+
+impl fmt::Show for Assertion {
+    /// Show the assertion as it would be written in a pattern.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            BeginText => r"\A",
+            EndText => r"\z",
+            EndTextNewline => r"\Z",
+            BeginLine => "(?m)^",
+            EndLine => "(?m)$",
+            WordBoundary => r"\b",
+            NotWordBoundary => r"\B",
+            UnicodeWordBoundary => r"(?u)\b",
+            NotUnicodeWordBoundary => r"(?u)\B"
+        };
+        write!(f.buf, "{}", s)
+    }
+}
+
+impl Assertion {
+    /// Check the assertion against the characters surrounding the
+    /// current position.
+    pub fn holds(&self, cx: &Context) -> bool {
+        match *self {
+            BeginText => cx.prev.is_none(),
+            EndText => cx.next.is_none(),
+            EndTextNewline => cx.next.is_none() || (cx.next == Some('\n') && cx.after.is_none()),
+            BeginLine => cx.prev.is_none() || cx.prev == Some('\n'),
+            EndLine => cx.next.is_none() || cx.next == Some('\n'),
+            WordBoundary => is_word(cx.prev, false) != is_word(cx.next, false),
+            NotWordBoundary => is_word(cx.prev, false) == is_word(cx.next, false),
+            UnicodeWordBoundary => is_word(cx.prev, true) != is_word(cx.next, true),
+            NotUnicodeWordBoundary => is_word(cx.prev, true) == is_word(cx.next, true)
+        }
+    }
+}
+
+
+/// Return `true` if the character is a word character, by either the
+/// ASCII or the Unicode definition.  The ends of the input count as
+/// non-word characters.
+#[inline]
+fn is_word(c: Option<char>, use_unicode: bool) -> bool {
+    match c {
+        Some(c) if use_unicode => unicode::word.includes(c),
+        Some(c) => ascii::word.includes(c),
+        None => false
+    }
+}
+
+
+/// The characters surrounding a position in the input.  `None` stands
+/// for either end of the input.
+pub struct Context {
+    /// The character just before the position.
+    prev: Option<char>,
+    /// The character just after the position.
+    next: Option<char>,
+    /// The character after that.
+    after: Option<char>
 }
 
 
@@ -39,9 +182,9 @@ impl Thread {
         }
     }
 
-    fn with_reg(&self, reg: uint, data: Option<u64>) -> Thread {
+    fn with_reg(&self, reg: uint, data: u64) -> Thread {
         let mut registers = self.registers.clone();
-        registers.grow_set(reg, &None, data);
+        registers.grow_set(reg, &None, Some(data));
         Thread {
             pc: 1 + self.pc,
             registers: registers
@@ -85,78 +228,266 @@ impl ThreadList {
 }
 
 
+///
 /// A regular expression virtual machine, loosely based on the Pike VM.
+///
+/// Assertions need to look at the characters on either side of the
+/// current position, so the VM lags one character behind its input:
+/// each character is only run once the one after it has been fed in.
+/// Call `finish` after the last character to flush it out.
+///
 pub struct VM<'a> {
     priv states: &'a [Inst],
-    priv index: Option<u64>,
+    priv index: u64,
+    priv prev: Option<char>,
+    priv lookahead: Option<char>,
+    priv queue: ~[Thread],
     priv threads: ThreadList,
-    priv next: ThreadList,
-    priv matched: bool
+    priv matched: Option<~[Option<u64>]>,
+    priv anchored: bool,
+    priv semantics: MatchSemantics
 }
 
 impl<'a> VM<'a> {
+    /// Create a VM that only matches at the start of the input.
     pub fn new(states: &'a [Inst]) -> VM<'a> {
-        let mut vm = VM {
+        VM::with_anchoring(states, true)
+    }
+
+    /// Create a VM that can match anywhere in the input.  The leftmost
+    /// match is preferred.
+    pub fn unanchored(states: &'a [Inst]) -> VM<'a> {
+        VM::with_anchoring(states, false)
+    }
+
+    fn with_anchoring(states: &'a [Inst], anchored: bool) -> VM<'a> {
+        VM {
             states: states,
-            index: None,
+            index: 0,
+            prev: None,
+            lookahead: None,
+            // Add the initial thread
+            queue: ~[Thread::new(0)],
             threads: ThreadList::new(),
-            next: ThreadList::new(),
-            matched: false
-        };
+            matched: None,
+            anchored: anchored,
+            semantics: LeftmostFirst
+        }
+    }
 
-        // Add the initial thread
-        vm.matched = follow(Thread::new(0), vm.index, vm.states, &mut vm.threads);
+    /// Choose which match to report.  This must be called before
+    /// anything is fed in.
+    pub fn with_semantics(mut self, semantics: MatchSemantics) -> VM<'a> {
+        self.semantics = semantics;
+        self
+    }
 
-        vm
+    ///
+    /// Throw away the current match and start looking for another one
+    /// part way through the input.  `index` is the byte offset of the
+    /// next character to be fed in, and `prev` is the character just
+    /// before it, if there is one.
+    ///
+    /// The VM's buffers are reused, which saves creating a new VM for
+    /// every match.
+    ///
+    pub fn restart(&mut self, index: u64, prev: Option<char>) {
+        self.index = index;
+        self.prev = prev;
+        self.lookahead = None;
+        self.queue.clear();
+        self.queue.push(Thread::new(0));
+        self.threads.clear();
+        self.matched = None;
     }
 
     /// Feed a character into the automaton.
     pub fn feed(&mut self, c: char) {
-        self.index.mutate_or_set(0, |i| 1 + i);
-        self.matched = false;
+        match self.lookahead {
+            Some(current) => self.step(current, Some(c)),
+            None => ()
+        }
+        self.lookahead = Some(c);
+    }
+
+    /// Signal the end of the input.  The VM must not be fed after this.
+    pub fn finish(&mut self) {
+        match self.lookahead {
+            Some(current) => self.step(current, None),
+            None => ()
+        }
+        self.lookahead = None;
+
+        let cx = Context { prev: self.prev, next: None, after: None };
+        self.expand(&cx);
+    }
+
+    /// Run the character at the current position.  `after` is the
+    /// character following it, if any.
+    fn step(&mut self, c: char, after: Option<char>) {
+        let cx = Context { prev: self.prev, next: Some(c), after: after };
+        self.expand(&cx);
+
+        self.index += c.len_utf8_bytes() as u64;
+        self.prev = Some(c);
 
         // Run through all the threads
         for t in self.threads.iter() {
             match self.states[t.pc] {
                 Range(lo, hi) => if lo <= c && c <= hi {
-                    if follow(t.with_pc(1 + t.pc), self.index, self.states, &mut self.next) {
-                        self.matched = true;
+                    self.queue.push(t.with_pc(1 + t.pc));
+                },
+                Class(ref cc) => if cc.includes(c) {
+                    self.queue.push(t.with_pc(1 + t.pc));
+                },
+                Jump(..) | Save(..) | Assert(..) | Match(..) => unreachable!()
+            }
+        }
+
+        // Start a new thread at this position, with the lowest
+        // priority.  Once something has matched, any match starting
+        // here would be further to the right, so don't bother.
+        if !self.anchored && self.matched.is_none() {
+            self.queue.push(Thread::new(0));
+        }
+    }
+
+    /// Follow the queued threads, in priority order, until they all
+    /// come to rest on a `Range` or `Class` instruction.
+    fn expand(&mut self, cx: &Context) {
+        self.threads.clear();
+        let queue = replace(&mut self.queue, ~[]);
+        // Leftmost-longest needs every thread that could still produce
+        // a longer match, not just the highest priority one
+        let cut = self.semantics != LeftmostLongest;
+        let mut found_here = false;
+        for t in queue.move_iter() {
+            if !cut && self.is_behind(&t) {
+                continue
+            }
+            match follow(t, self.index, cx, self.states, &mut self.threads, cut) {
+                Some(m) => match self.semantics {
+                    LeftmostFirst => {
+                        self.matched = Some(m.registers);
                         // Cut off lower priority threads
                         break
+                    },
+                    LeftmostLongest => {
+                        // Anything that matches here is longer than a
+                        // match from an earlier step, so prefer it unless
+                        // it starts later.  Threads are queued in order
+                        // of where they started, so within a step the
+                        // first match wins.
+                        let better = match self.matched {
+                            Some(ref best) => {
+                                let (start, best_start) = (self.start_of(&m), start_reg(*best));
+                                start < best_start || (start == best_start && !found_here)
+                            },
+                            None => true
+                        };
+                        if better {
+                            self.matched = Some(m.registers);
+                            found_here = true;
+                        }
+                    },
+                    Earliest => {
+                        self.matched = Some(m.registers);
+                        // Nothing else matters now
+                        self.threads.clear();
+                        break
                     }
                 },
-                Jump(..) | Save(..) => unreachable!()
+                None => ()
             }
         }
+    }
 
-        // Swap the thread buffers
-        swap(&mut self.threads, &mut self.next);
-        self.next.clear();
+    /// Return where a thread's match began.  Threads that haven't saved
+    /// it yet are just starting.
+    fn start_of(&self, t: &Thread) -> u64 {
+        if t.registers.is_empty() {
+            self.index
+        } else {
+            t.registers[0].unwrap_or(self.index)
+        }
+    }
+
+    /// Return `true` if a thread started after the current match, and
+    /// so can't beat it.
+    fn is_behind(&self, t: &Thread) -> bool {
+        match self.matched {
+            Some(ref best) => self.start_of(t) > start_reg(*best),
+            None => false
+        }
     }
 
-    /// Determine if we have a match, given the existing input.
+    /// Determine if we have a match, given the existing input.  Since
+    /// the VM lags behind by a character, a match ending at the last
+    /// character fed in won't be found until `feed` or `finish` is
+    /// called again.
     pub fn is_match(&self) -> bool {
-        self.matched
+        self.matched.is_some()
     }
-}
 
+    /// Return `true` if there are no threads left to run.  Feeding
+    /// more input after this point will not change the result.
+    pub fn is_done(&self) -> bool {
+        self.queue.is_empty() && (self.anchored || self.matched.is_some())
+    }
 
-/// Add all targets of the given thread to the thread list.
-/// Returns `true` if a matching state is reached; otherwise `false`.
-fn follow(t: Thread, index: Option<u64>, states: &[Inst], threads: &mut ThreadList) -> bool {
-    if t.pc == states.len() {
-        true
-    } else {
-        match states[t.pc] {
-            Jump(ref exits) => {
-                let mut matched = false;
-                for &exit in exits.iter() {
-                    matched |= follow(t.with_pc(exit), index, states, threads);
-                }
-                matched
+    ///
+    /// Return the registers of the best match found so far, or `None`
+    /// if nothing has matched yet.
+    ///
+    /// Each register holds the number of bytes of UTF-8 consumed when
+    /// the corresponding `Save` instruction was executed.  Registers that
+    /// were never saved are `None`, and trailing ones may be missing
+    /// altogether.
+    ///
+    pub fn registers<'b>(&'b self) -> Option<&'b [Option<u64>]> {
+        match self.matched {
+            Some(ref registers) => {
+                let r_borrow: &'b [Option<u64>] = *registers;
+                Some(r_borrow)
             },
-            Save(reg) => follow(t.with_reg(reg, index), index, states, threads),
-            Range(..) => { threads.add(t); false }
+            None => None
         }
     }
 }
+
+
+/// Return the start of a match, given its registers.
+fn start_reg(registers: &[Option<u64>]) -> u64 {
+    registers[0].expect("match without a start")
+}
+
+
+/// Add all targets of the given thread to the thread list.  Returns
+/// the first thread to reach a matching state, if any.  If `cut` is
+/// `true`, threads of lower priority than that one are not added.
+fn follow(t: Thread, index: u64, cx: &Context, states: &[Inst], threads: &mut ThreadList,
+          cut: bool) -> Option<Thread> {
+    match states[t.pc] {
+        Jump(ref exits) => {
+            let mut first = None;
+            for &exit in exits.iter() {
+                let m = follow(t.with_pc(exit), index, cx, states, threads, cut);
+                if first.is_none() && m.is_some() {
+                    first = m;
+                    if cut {
+                        break
+                    }
+                }
+            }
+            first
+        },
+        Save(reg) => follow(t.with_reg(reg, index), index, cx, states, threads, cut),
+        Assert(ref assertion) => if assertion.holds(cx) {
+            follow(t.with_pc(1 + t.pc), index, cx, states, threads, cut)
+        } else {
+            None
+        },
+        Match(..) => Some(t),
+        Range(..) | Class(..) => { threads.add(t); None }
+    }
+}